@@ -0,0 +1,156 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::config::config;
+use crate::input::workspace_dir;
+use crate::AocError;
+
+// Confirmed answers to the puzzles, to check that solutions still get them
+// right. Kept in `answers.toml` as a table per day, with the answers to an
+// example in a table named after its file:
+//
+//     [2023.4]
+//     part1 = 13
+//     part2 = "30"
+//
+//     [2023.4.example]
+//     part1 = 13
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers(BTreeMap<Key, String>);
+
+// Year, day, the example's file stem (None for the real input) and part
+type Key = (u16, u8, Option<String>, u8);
+
+pub fn answers_path() -> Result<PathBuf, AocError> {
+    Ok(match &config()?.answers_file {
+        Some(path) => path.clone(),
+        None => workspace_dir().join("answers.toml"),
+    })
+}
+
+impl Answers {
+    // A missing file has no answers yet
+    pub fn load(path: &Path) -> Result<Answers, AocError> {
+        match std::fs::read_to_string(path) {
+            Ok(s) => Answers::parse(&s)
+                .map_err(|e| AocError::Parse(format!("parse ({}): {e}", path.display()))),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(AocError::io("read answers", path, e)),
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<Answers, AocError> {
+        let years: BTreeMap<String, BTreeMap<String, toml::Table>> =
+            toml::from_str(s).map_err(|e| e.message().to_string())?;
+
+        let mut answers = Answers::default();
+        for (year, days) in years {
+            let year = year.parse().map_err(|_| format!("bad year {year}"))?;
+            for (day, table) in days {
+                let day = day.parse().map_err(|_| format!("bad day {day}"))?;
+                for (key, value) in table {
+                    match value {
+                        toml::Value::Table(example) => {
+                            for (part, answer) in example {
+                                answers.insert((year, day, Some(key.clone())), &part, answer)?;
+                            }
+                        }
+                        answer => answers.insert((year, day, None), &key, answer)?,
+                    }
+                }
+            }
+        }
+
+        Ok(answers)
+    }
+
+    fn insert(
+        &mut self,
+        (year, day, example): (u16, u8, Option<String>),
+        part: &str,
+        answer: toml::Value,
+    ) -> Result<(), AocError> {
+        let part = match part {
+            "part1" => 1,
+            "part2" => 2,
+            _ => {
+                return Err(AocError::Parse(format!(
+                    "{year} day {day}: unknown key {part}"
+                )))
+            }
+        };
+        // Numbers can be written as they are, without quotes
+        let answer = match answer {
+            toml::Value::String(s) => s,
+            toml::Value::Integer(n) => n.to_string(),
+            other => {
+                return Err(AocError::Parse(format!(
+                    "{year} day {day} part {part}: answer must be a string or integer, not {}",
+                    other.type_str()
+                )))
+            }
+        };
+
+        self.0.insert((year, day, example, part), answer);
+        Ok(())
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&(year, day, None, part)).map(String::as_str)
+    }
+
+    // The answer to the example in `src/{example}.txt`
+    pub fn example(&self, year: u16, day: u8, example: &str, part: u8) -> Option<&str> {
+        self.0
+            .get(&(year, day, Some(example.to_string()), part))
+            .map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_parses_answers() {
+        let answers = Answers::parse(
+            r#"
+            [2023.4]
+            part1 = 13
+            part2 = "30"
+
+            [2023.4.example]
+            part1 = 13
+
+            [2022.1]
+            part2 = "abc"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(2023, 4, 1), Some("13"));
+        assert_eq!(answers.get(2023, 4, 2), Some("30"));
+        assert_eq!(answers.get(2022, 1, 2), Some("abc"));
+        assert_eq!(answers.get(2022, 1, 1), None);
+        assert_eq!(answers.example(2023, 4, "example", 1), Some("13"));
+        assert_eq!(answers.example(2023, 4, "example", 2), None);
+        assert_eq!(answers.example(2023, 4, "example_2", 1), None);
+    }
+
+    #[test]
+    fn it_rejects_malformed_answers() {
+        assert!(Answers::parse("[2023.4]\npart3 = 1").is_err());
+        assert!(Answers::parse("[2023.x]\npart1 = 1").is_err());
+        assert!(Answers::parse("[2023.4]\npart1 = 1.5").is_err());
+        assert!(Answers::parse("[2023.4.example]\npart3 = 1").is_err());
+    }
+
+    #[test]
+    fn it_has_no_answers_without_a_file() {
+        assert_eq!(
+            Answers::load(Path::new("does/not/exist.toml")),
+            Ok(Answers::default())
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{cache, AocError, Verdict};
+
+// Answers already submitted for a part, so known wrong answers aren't sent
+// again. Kept in the cache as one `answer<TAB>verdict` line per attempt.
+#[derive(Debug, Default, PartialEq)]
+pub struct Attempts(Vec<(String, Verdict)>);
+
+pub fn attempts_file(dir: &Path, year: u16, day: u8, part: u8) -> PathBuf {
+    dir.join("attempts")
+        .join(year.to_string())
+        .join(format!("day_{day:02}_part_{part}.txt"))
+}
+
+impl Attempts {
+    pub fn load(path: &Path) -> Result<Attempts, AocError> {
+        match std::fs::read_to_string(path) {
+            Ok(s) => Ok(Attempts::parse(&s)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Attempts::default()),
+            Err(e) => Err(AocError::io("read attempts", path, e)),
+        }
+    }
+
+    fn parse(s: &str) -> Attempts {
+        Attempts(
+            s.lines()
+                .filter_map(|line| line.split_once('\t'))
+                .filter_map(|(answer, verdict)| {
+                    let verdict = match verdict {
+                        "correct" => Verdict::Correct,
+                        "too high" => Verdict::TooHigh,
+                        "too low" => Verdict::TooLow,
+                        "wrong" => Verdict::Wrong,
+                        _ => return None,
+                    };
+                    Some((answer.to_string(), verdict))
+                })
+                .collect(),
+        )
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        let lines: String = self
+            .0
+            .iter()
+            .filter_map(|(answer, verdict)| {
+                let verdict = match verdict {
+                    Verdict::Correct => "correct",
+                    Verdict::TooHigh => "too high",
+                    Verdict::TooLow => "too low",
+                    Verdict::Wrong => "wrong",
+                    _ => return None,
+                };
+                Some(format!("{answer}\t{verdict}\n"))
+            })
+            .collect();
+
+        cache::write(path, &lines)
+    }
+
+    // Only verdicts about the answer itself are worth remembering
+    pub fn record(&mut self, answer: &str, verdict: Verdict) {
+        if matches!(
+            verdict,
+            Verdict::Correct | Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong
+        ) {
+            self.0.push((answer.to_string(), verdict));
+        }
+    }
+
+    // The answer adventofcode.com accepted, once the part is solved
+    pub fn correct(&self) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(_, verdict)| *verdict == Verdict::Correct)
+            .map(|(answer, _)| answer.as_str())
+    }
+
+    // Refuses answers that are already known to be wrong, including numbers
+    // beyond an answer that was too high or too low
+    pub fn check(&self, answer: &str) -> Result<(), AocError> {
+        let number = answer.parse::<i64>().ok();
+
+        for (attempt, verdict) in &self.0 {
+            let beyond = match (number, attempt.parse::<i64>().ok(), verdict) {
+                (Some(n), Some(a), Verdict::TooHigh) => n >= a,
+                (Some(n), Some(a), Verdict::TooLow) => n <= a,
+                _ => false,
+            };
+
+            if *verdict == Verdict::Correct {
+                return Err(format!("already solved with {attempt}").into());
+            } else if attempt == answer {
+                return Err(format!("{answer} was already submitted: {verdict}").into());
+            } else if beyond {
+                return Err(format!("{answer} can't be right, {attempt} was {verdict}").into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// After a wrong answer adventofcode.com doesn't accept another one for a
+// while, the end of that wait is kept in the cache as a unix timestamp
+fn cooldown_file(dir: &Path) -> PathBuf {
+    dir.join("cooldown")
+}
+
+pub fn start_cooldown(dir: &Path, wait: Duration) -> Result<(), AocError> {
+    let until = SystemTime::now() + wait;
+    let secs = until
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+
+    cache::write(&cooldown_file(dir), &secs.to_string())
+}
+
+// Time left before another answer may be submitted, if any
+pub fn cooldown_left(dir: &Path) -> Option<Duration> {
+    let secs = std::fs::read_to_string(cooldown_file(dir))
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+
+    (UNIX_EPOCH + Duration::from_secs(secs))
+        .duration_since(SystemTime::now())
+        .ok()
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    fn attempts() -> Attempts {
+        let mut attempts = Attempts::default();
+        attempts.record("100", Verdict::TooHigh);
+        attempts.record("20", Verdict::TooLow);
+        attempts.record("50", Verdict::Wrong);
+        attempts.record("60", Verdict::Wait("1m".to_string()));
+        attempts
+    }
+
+    #[test]
+    fn it_refuses_known_wrong_answers() {
+        let attempts = attempts();
+
+        assert!(attempts.check("50").is_err());
+        assert!(attempts.check("100").is_err());
+        assert!(attempts.check("150").is_err());
+        assert!(attempts.check("10").is_err());
+        assert_eq!(attempts.check("60"), Ok(()));
+        assert_eq!(attempts.check("abc"), Ok(()));
+    }
+
+    #[test]
+    fn it_refuses_answers_to_solved_parts() {
+        let mut attempts = attempts();
+        assert_eq!(attempts.correct(), None);
+        attempts.record("42", Verdict::Correct);
+
+        assert_eq!(attempts.correct(), Some("42"));
+        assert_eq!(
+            attempts.check("43"),
+            Err(AocError::from("already solved with 42"))
+        );
+    }
+
+    #[test]
+    fn it_saves_and_loads_attempts() {
+        let dir = std::env::temp_dir().join(format!("aoc23-attempts-{}", std::process::id()));
+        let file = attempts_file(&dir, 2023, 4, 2);
+
+        assert_eq!(Attempts::load(&file), Ok(Attempts::default()));
+
+        attempts().save(&file).unwrap();
+        assert_eq!(Attempts::load(&file), Ok(attempts()));
+
+        start_cooldown(&dir, Duration::from_secs(60)).unwrap();
+        assert!(cooldown_left(&dir).is_some());
+
+        start_cooldown(&dir, Duration::ZERO).unwrap();
+        assert!(cooldown_left(&dir).is_none());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,67 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::config::config;
+use crate::AocError;
+
+// Downloads are kept in `~/.cache/aoc23` unless AOC_CACHE_DIR points elsewhere
+pub fn cache_dir() -> Result<PathBuf, AocError> {
+    if let Some(dir) = std::env::var_os("AOC_CACHE_DIR") {
+        return Ok(PathBuf::from(dir));
+    }
+
+    dirs::cache_dir()
+        .map(|dir| dir.join("aoc23"))
+        .ok_or(AocError::from("no cache directory, set AOC_CACHE_DIR"))
+}
+
+// Downloaded inputs go to the configured input_dir, or else the cache
+pub fn inputs_dir() -> Result<PathBuf, AocError> {
+    match &config()?.input_dir {
+        Some(dir) => Ok(dir.clone()),
+        None => cache_dir().map(|dir| dir.join("inputs")),
+    }
+}
+
+pub fn input_file(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(year.to_string()).join(format!("day_{day:02}.txt"))
+}
+
+pub fn write(path: &Path, contents: &str) -> Result<(), AocError> {
+    path.parent()
+        .map(std::fs::create_dir_all)
+        .transpose()
+        .and_then(|_| std::fs::write(path, contents))
+        .map_err(|e| AocError::io("write cache", path, e))
+}
+
+// How long ago a file was last written, None if it doesn't exist
+pub fn age(path: &Path) -> Option<Duration> {
+    let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok()?;
+
+    Some(modified.elapsed().unwrap_or_default())
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_names_cached_inputs_by_day() {
+        assert!(input_file(Path::new("inputs"), 2023, 4).ends_with("inputs/2023/day_04.txt"));
+    }
+
+    #[test]
+    fn it_tracks_file_age() {
+        let dir = std::env::temp_dir().join(format!("aoc23-cache-{}", std::process::id()));
+        let file = input_file(&dir, 2023, 1);
+
+        assert_eq!(age(&file), None);
+
+        write(&file, "input").unwrap();
+        assert!(age(&file).unwrap() < Duration::from_secs(60));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,178 @@
+use std::time::Duration;
+
+use crate::attempts::{self, Attempts};
+use crate::config::config;
+use crate::{cache, verdict, Answer, AocError, Verdict};
+
+const BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/sebastae/aoc23";
+
+// Requests to adventofcode.com are spaced out by at least this much, and a
+// cached leaderboard isn't downloaded again until it's older than
+// REFRESH_AFTER, which is as often as leaderboards may be fetched
+const REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+const REFRESH_AFTER: Duration = Duration::from_secs(15 * 60);
+
+// The session cookie of a logged in adventofcode.com user, from AOC_SESSION
+// or the configured session_file
+pub fn session() -> Result<String, AocError> {
+    if let Ok(session) = std::env::var("AOC_SESSION") {
+        return Ok(session.trim().to_string());
+    }
+
+    match &config()?.session_file {
+        Some(path) => std::fs::read_to_string(path)
+            .map(|s| s.trim().to_string())
+            .map_err(|e| AocError::io("read session", path, e)),
+        None => Err(AocError::from(
+            "AOC_SESSION is not set, copy it from the adventofcode.com session cookie",
+        )),
+    }
+}
+
+pub fn input_url(year: u16, day: u8) -> String {
+    format!("{BASE_URL}/{year}/day/{day}/input")
+}
+
+pub fn puzzle_url(year: u16, day: u8) -> String {
+    format!("{BASE_URL}/{year}/day/{day}")
+}
+
+pub fn answer_url(year: u16, day: u8) -> String {
+    format!("{BASE_URL}/{year}/day/{day}/answer")
+}
+
+pub fn leaderboard_url(year: u16, id: u64) -> String {
+    format!("{BASE_URL}/{year}/leaderboard/private/view/{id}.json")
+}
+
+// Downloads the input of a day into the cache, even if it's already there.
+// Inputs never change, so only the spacing of requests applies, not the
+// polling limit of leaderboards.
+pub fn fetch_input(year: u16, day: u8) -> Result<String, AocError> {
+    let file = cache::input_file(&cache::inputs_dir()?, year, day);
+
+    let input = get(&input_url(year, day))?;
+    cache::write(&file, &input)?;
+
+    Ok(input)
+}
+
+// The puzzle page of a day as HTML, including part 2 once it's unlocked
+pub fn fetch_puzzle(year: u16, day: u8) -> Result<String, AocError> {
+    get(&puzzle_url(year, day))
+}
+
+// Returns the cached input of a day, fetching it only if it isn't cached yet
+pub fn cached_input(year: u16, day: u8) -> Result<String, AocError> {
+    let file = cache::input_file(&cache::inputs_dir()?, year, day);
+
+    match std::fs::read_to_string(&file) {
+        Ok(input) => Ok(input),
+        Err(_) => fetch_input(year, day),
+    }
+}
+
+// The JSON of a private leaderboard, downloaded again only once the cached
+// copy is older than REFRESH_AFTER
+pub fn fetch_leaderboard(year: u16, id: u64) -> Result<String, AocError> {
+    let file = cache::cache_dir()?
+        .join("leaderboards")
+        .join(year.to_string())
+        .join(format!("{id}.json"));
+    if cache::age(&file).is_some_and(|age| age < REFRESH_AFTER) {
+        if let Ok(json) = std::fs::read_to_string(&file) {
+            return Ok(json);
+        }
+    }
+
+    let json = get(&leaderboard_url(year, id))?;
+    cache::write(&file, &json)?;
+
+    Ok(json)
+}
+
+// Submits an answer for a part and reports what adventofcode.com made of it.
+// Answers already known to be wrong aren't sent, and neither is anything
+// while adventofcode.com is still making us wait after the last answer.
+pub fn submit(year: u16, day: u8, part: u8, answer: &Answer) -> Result<Verdict, AocError> {
+    let answer = &answer.to_string();
+    let dir = cache::cache_dir()?;
+    let file = attempts::attempts_file(&dir, year, day, part);
+
+    let mut attempts = Attempts::load(&file)?;
+    attempts.check(answer)?;
+    if let Some(left) = attempts::cooldown_left(&dir) {
+        return Err(format!("wait {}s before submitting again", left.as_secs()).into());
+    }
+
+    let url = answer_url(year, day);
+    let response = authorized(ureq::post(&url))?
+        .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+    let body = read_body(&url, response)?;
+
+    if let Some(wait) = verdict::cooldown(&body) {
+        attempts::start_cooldown(&dir, wait)?;
+    }
+
+    let verdict = Verdict::from_response(&body);
+    attempts.record(answer, verdict.clone());
+    attempts.save(&file)?;
+
+    Ok(verdict)
+}
+
+// Waits until the previous request is at least REQUEST_INTERVAL ago
+fn throttle() -> Result<(), AocError> {
+    let marker = cache::cache_dir()?.join("last_request");
+    if let Some(age) = cache::age(&marker) {
+        std::thread::sleep(REQUEST_INTERVAL.saturating_sub(age));
+    }
+
+    cache::write(&marker, "")
+}
+
+fn get(url: &str) -> Result<String, AocError> {
+    let response = authorized(ureq::get(url))?.call();
+
+    read_body(url, response)
+}
+
+// Adds the session cookie to a request, once it may be sent
+fn authorized(request: ureq::Request) -> Result<ureq::Request, AocError> {
+    let session = session()?;
+    throttle()?;
+
+    Ok(request
+        .set("Cookie", &format!("session={session}"))
+        .set("User-Agent", USER_AGENT))
+}
+
+fn read_body(url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String, AocError> {
+    response
+        .map_err(|e| format!("fetch {url}: {e}"))?
+        .into_string()
+        .map_err(|e| AocError::from(format!("fetch {url}: {e}")))
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_builds_input_url() {
+        assert_eq!(
+            input_url(2023, 4),
+            "https://adventofcode.com/2023/day/4/input"
+        );
+    }
+
+    #[test]
+    fn it_builds_leaderboard_url() {
+        assert_eq!(
+            leaderboard_url(2023, 123456),
+            "https://adventofcode.com/2023/leaderboard/private/view/123456.json"
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+use crate::{AocError, DEFAULT_YEAR};
+
+// Settings from `aoc.toml` at the root of the workspace (or wherever
+// AOC_CONFIG points). Environment variables still win over the file.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub year: u16,
+    // File holding the session cookie, used when AOC_SESSION isn't set
+    pub session_file: Option<PathBuf>,
+    // Where downloaded inputs are kept instead of the cache directory
+    pub input_dir: Option<PathBuf>,
+    // Confirmed answers to check solutions against, `answers.toml` in the
+    // workspace when not set
+    pub answers_file: Option<PathBuf>,
+    // ID of the private leaderboard `aoc leaderboard` shows
+    pub leaderboard: Option<u64>,
+    // Arguments put in front of the ones the runner is given when running a
+    // day, so they can still conflict with them, e.g. `--day` with `--all`
+    pub default_args: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            year: DEFAULT_YEAR,
+            session_file: None,
+            input_dir: None,
+            answers_file: None,
+            leaderboard: None,
+            default_args: Vec::new(),
+        }
+    }
+}
+
+impl Config {
+    // A missing file is the same as an empty one. Relative paths in the file
+    // are relative to the directory it's in.
+    pub fn load(path: &Path) -> Result<Config, AocError> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(e) => return Err(AocError::io("read config", path, e)),
+        };
+
+        let mut config = Config::parse(&contents)
+            .map_err(|e| AocError::Parse(format!("parse config ({}): {e}", path.display())))?;
+
+        let base = path.parent().unwrap_or(Path::new("."));
+        config.session_file = config.session_file.map(|p| resolve(base, &p));
+        config.input_dir = config.input_dir.map(|p| resolve(base, &p));
+        config.answers_file = config.answers_file.map(|p| resolve(base, &p));
+
+        Ok(config)
+    }
+
+    fn parse(s: &str) -> Result<Config, AocError> {
+        toml::from_str(s).map_err(|e| AocError::Parse(e.message().to_string()))
+    }
+}
+
+fn resolve(base: &Path, path: &Path) -> PathBuf {
+    match path.strip_prefix("~") {
+        Ok(rest) => dirs::home_dir().unwrap_or_default().join(rest),
+        Err(_) => base.join(path),
+    }
+}
+
+pub fn config_path() -> PathBuf {
+    match std::env::var_os("AOC_CONFIG") {
+        Some(path) => PathBuf::from(path),
+        None => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("aoc.toml"),
+    }
+}
+
+// The configuration, loaded once on first use
+pub fn config() -> Result<&'static Config, AocError> {
+    static CONFIG: OnceLock<Result<Config, AocError>> = OnceLock::new();
+
+    CONFIG
+        .get_or_init(|| Config::load(&config_path()))
+        .as_ref()
+        .map_err(|e| e.clone())
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_parses_config() {
+        let config = Config::parse(
+            r#"
+            year = 2022
+            input_dir = "inputs"
+            leaderboard = 123456
+            default_args = ["--refresh"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config,
+            Config {
+                year: 2022,
+                session_file: None,
+                input_dir: Some(PathBuf::from("inputs")),
+                answers_file: None,
+                leaderboard: Some(123456),
+                default_args: vec!["--refresh".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn it_defaults_missing_settings() {
+        assert_eq!(Config::parse(""), Ok(Config::default()));
+        assert_eq!(
+            Config::load(Path::new("does/not/exist.toml")),
+            Ok(Config::default())
+        );
+    }
+
+    #[test]
+    fn it_rejects_unknown_settings() {
+        assert!(Config::parse("yaer = 2022").is_err());
+    }
+
+    #[test]
+    fn it_resolves_paths_relative_to_the_config() {
+        assert_eq!(
+            resolve(Path::new("/repo"), Path::new("inputs")),
+            PathBuf::from("/repo/inputs")
+        );
+        assert_eq!(
+            resolve(Path::new("/repo"), Path::new("/abs")),
+            PathBuf::from("/abs")
+        );
+    }
+}
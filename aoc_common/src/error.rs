@@ -0,0 +1,269 @@
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use thiserror::Error;
+
+// Everything that can go wrong solving a day or running the tooling around it.
+// Errors still read as plain messages describing what went wrong and where.
+#[derive(Debug, Clone, Error)]
+pub enum AocError {
+    // The input isn't in the shape the puzzle describes
+    #[error("{0}")]
+    Parse(String),
+
+    // A parse failure at a byte range of the input. The line and column the
+    // range starts at are 0 until `locate` finds them in the whole input.
+    #[error("{}", located(*.line, *.column, .message))]
+    ParseAt {
+        message: String,
+        span: Range<usize>,
+        line: usize,
+        column: usize,
+    },
+
+    // Reading or writing a file failed, e.g. `read config (aoc.toml): ...`
+    #[error("{action} ({}): {source}", path.display())]
+    Io {
+        action: &'static str,
+        path: PathBuf,
+        // Shared so errors can be cloned, like the cached config's
+        source: Arc<std::io::Error>,
+    },
+
+    // Every error found in an input when parsing carries on past the first,
+    // one per line
+    #[error("{}", lines(.0))]
+    Many(Vec<AocError>),
+
+    // The input file of a day, or of one of its examples, doesn't exist
+    #[error("read input ({}): no such file", .0.display())]
+    MissingInput(PathBuf),
+
+    // The error of a part without a solution yet, which runners can skip
+    #[error("part {0} not implemented")]
+    NotImplemented(u8),
+
+    // Anything else, described by its message
+    #[error("{0}")]
+    Message(String),
+}
+
+impl AocError {
+    pub fn io(action: &'static str, path: impl Into<PathBuf>, source: std::io::Error) -> AocError {
+        AocError::Io {
+            action,
+            path: path.into(),
+            source: Arc::new(source),
+        }
+    }
+
+    // Ok without any errors, otherwise fails with all of them, or the only one.
+    // Errors that are already several are flattened into the rest.
+    pub fn all(errors: Vec<AocError>) -> Result<(), AocError> {
+        let mut errors: Vec<AocError> = errors
+            .into_iter()
+            .flat_map(|e| match e {
+                AocError::Many(errors) => errors,
+                e => vec![e],
+            })
+            .collect();
+
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(errors.remove(0)),
+            _ => Err(AocError::Many(errors)),
+        }
+    }
+
+    pub fn parse_at(message: impl Into<String>, span: Range<usize>) -> AocError {
+        AocError::ParseAt {
+            message: message.into(),
+            span,
+            line: 0,
+            column: 0,
+        }
+    }
+
+    // Points a parse error that doesn't know where it is at `span`, other
+    // errors are kept as they are
+    pub fn at(self, span: Range<usize>) -> AocError {
+        match self {
+            AocError::Parse(message) => AocError::parse_at(message, span),
+            e => e,
+        }
+    }
+
+    // Moves a parse error from a part of the input to the whole of it, the
+    // part starting `offset` bytes in
+    pub fn offset(self, offset: usize) -> AocError {
+        match self {
+            AocError::ParseAt {
+                message,
+                span,
+                line: 0,
+                ..
+            } => AocError::parse_at(message, span.start + offset..span.end + offset),
+            AocError::Many(errors) => {
+                AocError::Many(errors.into_iter().map(|e| e.offset(offset)).collect())
+            }
+            e => e,
+        }
+    }
+
+    // Finds the line and column of a parse error in the input it was parsed
+    // from. Both count from 1, columns in characters.
+    pub fn locate(self, input: &str) -> AocError {
+        match self {
+            AocError::ParseAt {
+                message,
+                span,
+                line: 0,
+                ..
+            } => {
+                let before = input.get(..span.start).unwrap_or(input);
+                let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+                AocError::ParseAt {
+                    message,
+                    line: before.matches('\n').count() + 1,
+                    column: before[line_start..].chars().count() + 1,
+                    span,
+                }
+            }
+            AocError::Many(errors) => {
+                AocError::Many(errors.into_iter().map(|e| e.locate(input)).collect())
+            }
+            e => e,
+        }
+    }
+}
+
+fn lines(errors: &[AocError]) -> String {
+    errors
+        .iter()
+        .map(AocError::to_string)
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn located(line: usize, column: usize, message: &str) -> String {
+    if line == 0 {
+        message.to_string()
+    } else {
+        format!("line {line}, column {column}: {message}")
+    }
+}
+
+// Errors are equal when they're the same kind of error saying the same thing,
+// about the same part of the input for parse errors
+impl PartialEq for AocError {
+    fn eq(&self, other: &AocError) -> bool {
+        if let (
+            AocError::ParseAt { span, .. },
+            AocError::ParseAt {
+                span: other_span, ..
+            },
+        ) = (self, other)
+        {
+            if span != other_span {
+                return false;
+            }
+        }
+
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+            && self.to_string() == other.to_string()
+    }
+}
+
+impl From<String> for AocError {
+    fn from(message: String) -> AocError {
+        AocError::Message(message)
+    }
+}
+
+impl From<&str> for AocError {
+    fn from(message: &str) -> AocError {
+        AocError::Message(message.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_describes_errors() {
+        let e = AocError::io(
+            "read config",
+            "aoc.toml",
+            std::io::Error::from(std::io::ErrorKind::PermissionDenied),
+        );
+
+        assert_eq!(e.to_string(), "read config (aoc.toml): permission denied");
+        assert_eq!(
+            AocError::MissingInput(PathBuf::from("day_04/src/input.txt")).to_string(),
+            "read input (day_04/src/input.txt): no such file"
+        );
+        assert_eq!(
+            AocError::NotImplemented(2).to_string(),
+            "part 2 not implemented"
+        );
+    }
+
+    #[test]
+    fn it_compares_kind_and_message() {
+        assert_eq!(AocError::from("split line"), AocError::from("split line"));
+        assert_ne!(
+            AocError::Parse("split line".to_string()),
+            AocError::from("split line")
+        );
+
+        let e = AocError::io("write cache", "x", std::io::Error::other("disk full"));
+        assert_eq!(e.clone(), e);
+        assert_ne!(
+            AocError::parse_at("split line", 0..4),
+            AocError::parse_at("split line", 0..5)
+        );
+    }
+
+    #[test]
+    fn it_locates_parse_errors() {
+        let input = "Card 1: 1 | 2\nCard 2: 1 x | 3\n";
+
+        // The `x` on the second line, which starts 14 bytes in
+        let e = AocError::parse_at("bad number", 10..11)
+            .offset(14)
+            .locate(input);
+
+        assert_eq!(e.to_string(), "line 2, column 11: bad number");
+        assert_eq!(e, AocError::parse_at("bad number", 24..25).locate(input));
+        // Once located an error stays where it is
+        assert_eq!(e.clone().offset(3).locate(""), e);
+        assert_eq!(
+            AocError::Parse("split line".to_string()).at(0..6),
+            AocError::parse_at("split line", 0..6)
+        );
+    }
+
+    #[test]
+    fn it_gathers_errors() {
+        let input = "a\nb\nc";
+        let errors = vec![
+            AocError::parse_at("bad a", 0..1),
+            AocError::parse_at("bad c", 4..5),
+        ];
+
+        assert_eq!(AocError::all(Vec::new()), Ok(()));
+        assert_eq!(AocError::all(errors[..1].to_vec()), Err(errors[0].clone()));
+        assert_eq!(
+            AocError::all(vec![AocError::Many(errors.clone())]),
+            Err(AocError::Many(errors.clone()))
+        );
+        assert_eq!(
+            AocError::all(errors).unwrap_err().locate(input).to_string(),
+            "line 1, column 1: bad a\nline 3, column 1: bad c"
+        );
+    }
+}
@@ -0,0 +1,66 @@
+// Just enough HTML handling for the pages of adventofcode.com and the reports
+// made from the results
+
+// Drops all tags, keeping only the text between them
+pub fn strip_tags(html: &str) -> String {
+    let mut in_tag = false;
+    html.chars()
+        .filter(|&c| match c {
+            '<' => {
+                in_tag = true;
+                false
+            }
+            '>' if in_tag => {
+                in_tag = false;
+                false
+            }
+            _ => !in_tag,
+        })
+        .collect()
+}
+
+pub fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+pub fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_strips_tags() {
+        assert_eq!(strip_tags("<p>a <em>b</em></p> > c"), "a b > c");
+    }
+
+    #[test]
+    fn it_unescapes_entities() {
+        assert_eq!(
+            unescape("&lt;a&gt; &amp;lt; &quot;b&quot;"),
+            "<a> &lt; \"b\""
+        );
+    }
+
+    #[test]
+    fn it_escapes_text() {
+        let text = "<a href=\"x\">&'</a>";
+
+        assert_eq!(
+            escape(text),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&#39;&lt;/a&gt;"
+        );
+        assert_eq!(unescape(&escape(text)), text);
+    }
+}
@@ -0,0 +1,146 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use crate::{AocError, DEFAULT_YEAR};
+
+pub fn workspace_dir() -> PathBuf {
+    let common = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    common.parent().unwrap_or(common).to_path_buf()
+}
+
+// Where the crate of a day is in the workspace. Days of 2023 are at the root,
+// other years in a directory named after the year, e.g. `2024/day_01`.
+pub fn day_member(year: u16, day: u8) -> String {
+    if year == DEFAULT_YEAR {
+        format!("day_{day:02}")
+    } else {
+        format!("{year}/day_{day:02}")
+    }
+}
+
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    workspace_dir().join(day_member(year, day))
+}
+
+// Package names have to be unique in the workspace, so other years than 2023
+// prefix theirs with the year, e.g. `y2024_day_01`
+pub fn day_crate(year: u16, day: u8) -> String {
+    if year == DEFAULT_YEAR {
+        format!("day_{day:02}")
+    } else {
+        format!("y{year}_day_{day:02}")
+    }
+}
+
+// The puzzle input of a day lives next to its solution, in `day_NN/src/input.txt`
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    day_dir(year, day).join("src").join("input.txt")
+}
+
+// Examples from the puzzle live next to the input, e.g. `src/example.txt` or
+// `src/example_2.txt`, and are named by their file stem
+pub fn example_path(year: u16, day: u8, example: &str) -> PathBuf {
+    day_dir(year, day)
+        .join("src")
+        .join(format!("{example}.txt"))
+}
+
+pub fn read_input(path: impl AsRef<Path>) -> Result<String, AocError> {
+    let path = path.as_ref();
+
+    match std::fs::read_to_string(path) {
+        Ok(s) => Ok(normalize_newlines(&s)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            Err(AocError::MissingInput(path.to_path_buf()))
+        }
+        Err(e) => Err(AocError::io("read input", path, e)),
+    }
+}
+
+// Reads the input of a day. An `input.txt` next to the solution wins, otherwise
+// the input is taken from the cache and downloaded with AOC_SESSION on first
+// use, since inputs aren't committed.
+pub fn load_input(year: u16, day: u8) -> Result<String, AocError> {
+    let path = input_path(year, day);
+    if path.exists() {
+        return read_input(path);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    return crate::client::cached_input(year, day).map(|input| normalize_newlines(&input));
+
+    #[cfg(target_arch = "wasm32")]
+    Err(AocError::MissingInput(path))
+}
+
+pub fn read_stdin() -> Result<String, AocError> {
+    let mut input = String::new();
+    std::io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|e| AocError::io("read input", "stdin", e))?;
+
+    Ok(normalize_newlines(&input))
+}
+
+// The input to solve: the given file, or else the day's own input. Stdin is
+// only read when asked for with `-`, since an open stdin may not be meant as
+// input at all, like the refs git pipes into a pre-push hook.
+pub fn select_input(year: u16, day: u8, path: Option<&Path>) -> Result<String, AocError> {
+    match path {
+        Some(path) if path == Path::new("-") => read_stdin(),
+        Some(path) => read_input(path),
+        None => load_input(year, day),
+    }
+}
+
+// The path following `--input` on the command line, for binaries that don't
+// parse their arguments with clap
+pub fn input_arg() -> Option<PathBuf> {
+    std::env::args()
+        .skip_while(|a| a != "--input")
+        .nth(1)
+        .map(PathBuf::from)
+}
+
+// Remove carriage-returns because windows >:(
+pub fn normalize_newlines(s: &str) -> String {
+    s.replace('\r', "")
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_builds_input_path() {
+        assert!(input_path(2023, 4).ends_with("day_04/src/input.txt"));
+        assert!(input_path(2024, 4).ends_with("2024/day_04/src/input.txt"));
+    }
+
+    #[test]
+    fn it_names_day_crates() {
+        assert_eq!(day_crate(2023, 4), "day_04");
+        assert_eq!(day_crate(2024, 4), "y2024_day_04");
+        assert_eq!(day_member(2024, 4), "2024/day_04");
+    }
+
+    #[test]
+    fn it_normalizes_newlines() {
+        assert_eq!(normalize_newlines("a\r\nb\r\n\r\nc"), "a\nb\n\nc");
+    }
+
+    #[test]
+    fn it_reports_missing_input() {
+        let err = read_input("does/not/exist.txt").unwrap_err();
+
+        assert_eq!(
+            err,
+            AocError::MissingInput(PathBuf::from("does/not/exist.txt"))
+        );
+        assert!(err
+            .to_string()
+            .starts_with("read input (does/not/exist.txt)"));
+    }
+}
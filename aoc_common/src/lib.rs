@@ -0,0 +1,28 @@
+// Lets the #[aoc] attribute refer to aoc_common from inside this crate too
+extern crate self as aoc_common;
+
+pub mod answers;
+pub mod attempts;
+pub mod cache;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod client;
+pub mod config;
+mod error;
+pub mod html;
+pub mod input;
+pub mod parse;
+pub mod progress;
+pub mod registry;
+mod solution;
+pub mod testing;
+pub mod verdict;
+
+pub use aoc_macros::aoc;
+pub use error::AocError;
+pub use solution::{
+    is_not_implemented, not_implemented, Answer, AnySolution, IntoAnswer, Parsed, Solution,
+};
+pub use verdict::Verdict;
+
+// The year the days at the root of the workspace are from
+pub const DEFAULT_YEAR: u16 = 2023;
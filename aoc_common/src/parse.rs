@@ -0,0 +1,72 @@
+use std::fmt::Display;
+use std::ops::Range;
+use std::str::FromStr;
+
+use crate::AocError;
+
+// The byte offset of `part` in `s`, which it has to be a slice of
+pub fn offset_of(s: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).wrapping_sub(s.as_ptr() as usize);
+    debug_assert!(offset <= s.len(), "not a slice of the string");
+
+    offset
+}
+
+// The byte range `part` covers in `s`, which it has to be a slice of
+pub fn span_of(s: &str, part: &str) -> Range<usize> {
+    let start = offset_of(s, part);
+
+    start..start + part.len()
+}
+
+// Parse every whitespace separated number in the string. A number that fails
+// to parse is pointed at in the string.
+pub fn parse_numbers<T>(s: &str) -> Result<Vec<T>, AocError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    s.split_ascii_whitespace()
+        .map(|n| {
+            n.parse::<T>()
+                .map_err(|e| AocError::parse_at(format!("parse number ({n}): {e}"), span_of(s, n)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_parses_numbers() {
+        assert_eq!(
+            parse_numbers::<u32>(" 1 22  333\t4\n"),
+            Ok(vec![1, 22, 333, 4])
+        );
+        assert_eq!(parse_numbers::<i64>("-5 5"), Ok(vec![-5, 5]));
+        assert_eq!(parse_numbers::<u8>(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn it_finds_offsets_of_slices() {
+        let s = "Card 1: 41 48";
+        let (_, numbers) = s.split_once(':').unwrap();
+
+        assert_eq!(offset_of(s, numbers), 7);
+        assert_eq!(offset_of(s, s), 0);
+        assert_eq!(span_of(s, numbers.trim()), 8..13);
+    }
+
+    #[test]
+    fn it_reports_the_failing_number() {
+        assert_eq!(
+            parse_numbers::<u32>("1 x 3"),
+            Err(AocError::parse_at(
+                "parse number (x): invalid digit found in string",
+                2..3
+            ))
+        );
+    }
+}
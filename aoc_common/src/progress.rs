@@ -0,0 +1,81 @@
+use std::io::IsTerminal;
+
+use indicatif::{ProgressBar, ProgressBarIter, ProgressStyle};
+
+// Progress of a long loop, like a brute force over millions of seeds:
+//
+//     let progress = Progress::new(seeds.len() as u64, "seeds");
+//     for seed in progress.wrap(seeds.iter()) { ... }
+//
+// or `progress.inc(1)` from inside the loop, which works from rayon's threads
+// too. The bar is drawn on stderr and only when stdout is a terminal, so
+// piped output and tests stay clean. It's cleared once dropped.
+pub struct Progress(ProgressBar);
+
+impl Progress {
+    pub fn new(len: u64, message: &str) -> Progress {
+        let bar = if std::io::stdout().is_terminal() {
+            ProgressBar::new(len)
+        } else {
+            ProgressBar::hidden()
+        };
+        bar.set_length(len);
+        bar.set_message(message.to_string());
+        bar.set_style(
+            ProgressStyle::with_template(
+                "{msg} [{elapsed_precise}] {wide_bar} {human_pos}/{human_len} ({eta})",
+            )
+            .unwrap_or_else(|_| ProgressStyle::default_bar()),
+        );
+
+        Progress(bar)
+    }
+
+    pub fn inc(&self, n: u64) {
+        self.0.inc(n);
+    }
+
+    pub fn position(&self) -> u64 {
+        self.0.position()
+    }
+
+    // Advances the bar by one for every item taken from the iterator, and
+    // completes it once the iterator runs out
+    pub fn wrap<I: Iterator>(&self, iter: I) -> ProgressBarIter<I> {
+        self.0.wrap_iter(iter)
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        self.0.finish_and_clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_counts_progress() {
+        let progress = Progress::new(10, "seeds");
+        progress.inc(1);
+        progress.inc(2);
+
+        assert_eq!(progress.position(), 3);
+    }
+
+    #[test]
+    fn it_wraps_iterators() {
+        let progress = Progress::new(10, "seeds");
+
+        let mut numbers = progress.wrap(0..10);
+        numbers.next();
+        numbers.next();
+        assert_eq!(progress.position(), 2);
+
+        assert_eq!(numbers.sum::<u64>(), 44);
+        assert_eq!(progress.position(), 10);
+    }
+}
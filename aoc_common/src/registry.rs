@@ -0,0 +1,177 @@
+use crate::{not_implemented, Answer, AnySolution, AocError, Parsed};
+
+#[doc(hidden)]
+pub use inventory;
+
+// A day's solution as registered by its crate with `register!`
+pub struct Registration {
+    pub year: u16,
+    pub day: u8,
+    pub solution: &'static dyn AnySolution,
+}
+
+inventory::collect!(Registration);
+
+// A single part registered with the `#[aoc(day = N, part = P)]` attribute
+pub struct Part {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub solve: fn(&str) -> Result<Answer, AocError>,
+}
+
+inventory::collect!(Part);
+
+// Registers a solution for a day, e.g. `register!(2023, 4, Day04);`, the year
+// defaulting to 2023 when left out. The solution is picked up by anything
+// linking the day's crate.
+#[macro_export]
+macro_rules! register {
+    ($year:expr, $day:expr, $solution:expr) => {
+        $crate::registry::inventory::submit! {
+            $crate::registry::Registration {
+                year: $year,
+                day: $day,
+                solution: &$solution,
+            }
+        }
+    };
+    ($day:expr, $solution:expr) => {
+        $crate::register!($crate::DEFAULT_YEAR, $day, $solution);
+    };
+}
+
+// All registered solutions, ordered by year and day
+pub fn registrations() -> Vec<&'static Registration> {
+    let mut registrations: Vec<_> = inventory::iter::<Registration>.into_iter().collect();
+    registrations.sort_by_key(|r| (r.year, r.day));
+    registrations
+}
+
+// Every day with a Solution or #[aoc] part functions, ordered by year and day
+pub fn days() -> Vec<(u16, u8)> {
+    let mut days: Vec<_> = inventory::iter::<Registration>
+        .into_iter()
+        .map(|r| (r.year, r.day))
+        .chain(inventory::iter::<Part>.into_iter().map(|p| (p.year, p.day)))
+        .collect();
+    days.sort();
+    days.dedup();
+    days
+}
+
+// A registered day, solved either by its Solution or, for days without one,
+// by its #[aoc] part functions
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    solution: Option<&'static dyn AnySolution>,
+}
+
+pub fn find(year: u16, day: u8) -> Option<Day> {
+    let solution = inventory::iter::<Registration>
+        .into_iter()
+        .find(|r| r.year == year && r.day == day)
+        .map(|r| r.solution);
+
+    let has_parts = inventory::iter::<Part>
+        .into_iter()
+        .any(|p| p.year == year && p.day == day);
+
+    (solution.is_some() || has_parts).then_some(Day {
+        year,
+        day,
+        solution,
+    })
+}
+
+impl Day {
+    // Part functions take the raw input, so for them the input is kept as is
+    pub fn parse(&self, input: &str) -> Result<Parsed, AocError> {
+        let _span = tracing::info_span!("parse", year = self.year, day = self.day).entered();
+
+        match self.solution {
+            Some(solution) => solution.parse(input),
+            None => Ok(Box::new(input.to_string())),
+        }
+    }
+
+    pub fn solve(&self, part: u8, parsed: &Parsed) -> Result<Answer, AocError> {
+        let _span = tracing::info_span!("solve", year = self.year, day = self.day, part).entered();
+
+        if let Some(solution) = self.solution {
+            return solution.solve(part, parsed);
+        }
+
+        let p = inventory::iter::<Part>
+            .into_iter()
+            .find(|p| p.year == self.year && p.day == self.day && p.part == part)
+            .ok_or(not_implemented(part))?;
+        let input = parsed
+            .downcast_ref::<String>()
+            .ok_or(AocError::from("input was parsed by another solution"))?;
+
+        (p.solve)(input)
+    }
+}
+
+// Parses the input and solves a part of a day
+pub fn solve(year: u16, day: u8, part: u8, input: &str) -> Result<Answer, AocError> {
+    let day = find(year, day).ok_or(format!("no solution for {year} day {day}"))?;
+    let parsed = day.parse(input)?;
+
+    day.solve(part, &parsed)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::registry::*;
+    use crate::{aoc, Solution};
+
+    struct Dummy;
+
+    impl Solution for Dummy {
+        type Parsed = usize;
+
+        fn parse(&self, input: &str) -> Result<Self::Parsed, AocError> {
+            Ok(input.len())
+        }
+
+        fn part1(&self, len: &Self::Parsed) -> Result<Answer, AocError> {
+            Ok(Answer::from(*len))
+        }
+    }
+
+    register!(25, Dummy);
+    register!(2024, 1, Dummy);
+
+    #[aoc(year = 2022, day = 24, part = 2)]
+    fn count_lines(input: &str) -> usize {
+        input.lines().count()
+    }
+
+    #[test]
+    fn it_finds_registered_solutions() {
+        let day = find(2023, 25).unwrap();
+        assert_eq!(
+            day.solve(1, &day.parse("abc").unwrap()),
+            Ok(Answer::Unsigned(3))
+        );
+        assert!(find(2023, 24).is_none());
+        assert!(find(2024, 1).is_some());
+        assert!(find(2023, 1).is_none());
+        assert!(registrations()
+            .iter()
+            .any(|r| r.year == 2023 && r.day == 25));
+        assert_eq!(days(), vec![(2022, 24), (2023, 25), (2024, 1)]);
+    }
+
+    #[test]
+    fn it_solves_registered_parts() {
+        assert_eq!(solve(2023, 25, 1, "abc"), Ok(Answer::Unsigned(3)));
+        assert_eq!(solve(2022, 24, 2, "a\nb"), Ok(Answer::Unsigned(2)));
+        assert!(solve(2023, 24, 2, "a\nb").is_err());
+        assert!(solve(2022, 24, 1, "").is_err());
+        assert!(solve(2023, 25, 2, "").is_err());
+    }
+}
@@ -0,0 +1,224 @@
+use std::any::Any;
+use std::fmt::Display;
+
+use serde::{Serialize, Serializer};
+
+use crate::AocError;
+
+// The answer to a part. Most are numbers, some are text, like the letters
+// drawn by a grid of pixels.
+#[derive(Clone, Debug, Eq)]
+pub enum Answer {
+    Unsigned(u128),
+    Signed(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+// Numbers are equal whatever type they were solved as, so a count in an i32
+// still matches the same count in a usize
+impl PartialEq for Answer {
+    fn eq(&self, other: &Answer) -> bool {
+        match (self, other) {
+            (Answer::Unsigned(a), Answer::Signed(b)) | (Answer::Signed(b), Answer::Unsigned(a)) => {
+                u128::try_from(*b) == Ok(*a)
+            }
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => a == b,
+            (Answer::Signed(a), Answer::Signed(b)) => a == b,
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+// Numbers as JSON numbers, text as strings
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Unsigned(n) => serializer.serialize_u128(*n),
+            Answer::Signed(n) => serializer.serialize_i128(*n),
+            Answer::Text(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident: $($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Answer {
+                Answer::$variant(n as _)
+            }
+        })*
+    };
+}
+
+impl_from!(Unsigned: u8, u16, u32, u64, u128, usize);
+impl_from!(Signed: i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Text(s.to_string())
+    }
+}
+
+// A day's solution. The input is parsed once, on its own so it can be timed,
+// and both parts solve from the parsed input.
+pub trait Solution {
+    type Parsed;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, AocError>;
+
+    fn part1(&self, parsed: &Self::Parsed) -> Result<Answer, AocError>;
+
+    fn part2(&self, _parsed: &Self::Parsed) -> Result<Answer, AocError> {
+        Err(not_implemented(2))
+    }
+}
+
+// The error of a part without a solution yet, which runners can skip
+pub fn not_implemented(part: u8) -> AocError {
+    AocError::NotImplemented(part)
+}
+
+pub fn is_not_implemented(e: &AocError) -> bool {
+    matches!(e, AocError::NotImplemented(_))
+}
+
+// Parsed input of any day, as handed around by the registry
+pub type Parsed = Box<dyn Any>;
+
+// Solution with the parsed type erased, so different days can be registered
+// and run alike. Implemented for every Solution, with parse errors located
+// in the input.
+pub trait AnySolution: Sync {
+    fn parse(&self, input: &str) -> Result<Parsed, AocError>;
+
+    fn solve(&self, part: u8, parsed: &Parsed) -> Result<Answer, AocError>;
+}
+
+impl<S> AnySolution for S
+where
+    S: Solution + Sync,
+    S::Parsed: 'static,
+{
+    fn parse(&self, input: &str) -> Result<Parsed, AocError> {
+        Solution::parse(self, input)
+            .map(|parsed| Box::new(parsed) as Parsed)
+            .map_err(|e| e.locate(input))
+    }
+
+    fn solve(&self, part: u8, parsed: &Parsed) -> Result<Answer, AocError> {
+        let parsed = parsed
+            .downcast_ref::<S::Parsed>()
+            .ok_or(AocError::from("input was parsed by another solution"))?;
+
+        match part {
+            1 => self.part1(parsed),
+            2 => self.part2(parsed),
+            _ => Err(format!("no part {part}").into()),
+        }
+    }
+}
+
+// Whatever a solver function returns that can be reported as an answer
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Answer, AocError>;
+}
+
+macro_rules! impl_into_answer {
+    ($($t:ty),*) => {
+        $(impl IntoAnswer for $t {
+            fn into_answer(self) -> Result<Answer, AocError> {
+                Ok(Answer::from(self))
+            }
+        })*
+    };
+}
+
+impl_into_answer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, String, &str);
+
+impl<T: IntoAnswer> IntoAnswer for Option<T> {
+    fn into_answer(self) -> Result<Answer, AocError> {
+        self.ok_or(AocError::from("no answer"))?.into_answer()
+    }
+}
+
+impl<T: IntoAnswer, E: ToString> IntoAnswer for Result<T, E> {
+    fn into_answer(self) -> Result<Answer, AocError> {
+        self.map_err(|e| e.to_string())?.into_answer()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        type Parsed = Vec<String>;
+
+        fn parse(&self, input: &str) -> Result<Self::Parsed, AocError> {
+            Ok(input.lines().map(String::from).collect())
+        }
+
+        fn part1(&self, lines: &Self::Parsed) -> Result<Answer, AocError> {
+            Ok(lines.len().into())
+        }
+    }
+
+    #[test]
+    fn it_solves_from_erased_input() {
+        let solution: &dyn AnySolution = &Lines;
+        let parsed = solution.parse("a\nb").unwrap();
+
+        assert_eq!(solution.solve(1, &parsed), Ok(Answer::Unsigned(2)));
+        assert_eq!(solution.solve(2, &parsed), Err(AocError::NotImplemented(2)));
+        assert!(solution.solve(1, &(Box::new(2) as Parsed)).is_err());
+    }
+
+    #[test]
+    fn it_tells_unimplemented_parts_apart() {
+        let e = Lines.part2(&Vec::new()).unwrap_err();
+
+        assert!(is_not_implemented(&e));
+        assert!(!is_not_implemented(&AocError::from(
+            "part 2 not implemented"
+        )));
+    }
+
+    #[test]
+    fn it_converts_answers() {
+        assert_eq!(42u32.into_answer(), Ok(Answer::Unsigned(42)));
+        assert_eq!(Some(-1i64).into_answer(), Ok(Answer::Signed(-1)));
+        assert_eq!("EHZ".into_answer(), Ok(Answer::Text("EHZ".to_string())));
+        assert_eq!(None::<u32>.into_answer(), Err(AocError::from("no answer")));
+        assert_eq!(
+            Err::<u32, _>("bad input").into_answer(),
+            Err(AocError::from("bad input"))
+        );
+    }
+
+    #[test]
+    fn it_compares_answers_of_any_type() {
+        assert_eq!(Answer::from(13u32), Answer::from(13i64));
+        assert_ne!(Answer::from(13u32), Answer::from(-13i64));
+        assert_ne!(Answer::from(13u32), Answer::from("EHZ"));
+        assert_eq!(Answer::from(-7i32).to_string(), "-7");
+    }
+}
@@ -0,0 +1,32 @@
+// Helpers for the tests of the days
+
+// Every rotation of the input lines, both forwards and reversed, to check
+// that answers don't depend on the order of the lines
+pub fn reorder_lines(input: &str) -> Vec<String> {
+    let lines: Vec<&str> = input.lines().collect();
+
+    (0..lines.len())
+        .flat_map(|i| {
+            let mut rotated = lines.clone();
+            rotated.rotate_left(i);
+            let reversed: Vec<&str> = rotated.iter().rev().copied().collect();
+
+            [rotated.join("\n"), reversed.join("\n")]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_reorders_lines() {
+        assert_eq!(
+            reorder_lines("a\nb\nc"),
+            vec!["a\nb\nc", "c\nb\na", "b\nc\na", "a\nc\nb", "c\na\nb", "b\na\nc"]
+        );
+        assert!(reorder_lines("").is_empty());
+    }
+}
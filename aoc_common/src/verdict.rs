@@ -0,0 +1,157 @@
+use std::fmt::Display;
+use std::time::Duration;
+
+use crate::html;
+
+// What adventofcode.com said about a submitted answer
+#[derive(Clone, Debug, PartialEq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    // Submitted too soon after the last answer, with the time left to wait
+    Wait(String),
+    AlreadySolved,
+    Unknown(String),
+}
+
+impl Verdict {
+    // Reads the verdict out of the page returned after posting an answer
+    pub fn from_response(body: &str) -> Verdict {
+        let article = body
+            .split_once("<article>")
+            .and_then(|(_, rest)| rest.split_once("</article>"))
+            .map_or(body, |(article, _)| article);
+
+        if article.contains("That's the right answer") {
+            Verdict::Correct
+        } else if article.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if article.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if article.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if article.contains("You gave an answer too recently") {
+            let left = article
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map_or("some time", |(left, _)| left);
+            Verdict::Wait(left.to_string())
+        } else if article.contains("Did you already complete it?") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown(html::strip_tags(article).trim().to_string())
+        }
+    }
+}
+
+// How long until another answer may be submitted, either because one was
+// given too recently ("You have 4m 32s left to wait") or because a wrong
+// answer was just given ("please wait 5 minutes before trying again")
+pub fn cooldown(body: &str) -> Option<Duration> {
+    if let Some((_, rest)) = body.split_once("You have ") {
+        let (left, _) = rest.split_once(" left to wait")?;
+
+        return left
+            .split_whitespace()
+            .map(|part| {
+                let (n, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+                let n = n.parse::<u64>().ok()?;
+                match unit {
+                    "h" => Some(n * 3600),
+                    "m" => Some(n * 60),
+                    "s" => Some(n),
+                    _ => None,
+                }
+            })
+            .sum::<Option<u64>>()
+            .map(Duration::from_secs);
+    }
+
+    let (_, rest) = body.split_once("please wait ")?;
+    let (wait, _) = rest.split_once(" before trying again")?;
+    let minutes = match wait.split_whitespace().next()? {
+        "one" => 1,
+        n => n.parse::<u64>().ok()?,
+    };
+
+    Some(Duration::from_secs(minutes * 60))
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "right answer!"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait(left) => write!(f, "answered too recently, wait {left}"),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            Verdict::Unknown(text) => write!(f, "unrecognized response: {text}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    fn page(article: &str) -> String {
+        format!("<html><main><article><p>{article}</p></article></main></html>")
+    }
+
+    #[test]
+    fn it_reads_cooldowns() {
+        let cases = [
+            ("You have 4m 32s left to wait.", Some(272)),
+            ("You have 30s left to wait.", Some(30)),
+            ("please wait one minute before trying again.", Some(60)),
+            ("please wait 5 minutes before trying again.", Some(300)),
+            ("That's the right answer!", None),
+        ];
+
+        for (article, secs) in cases {
+            assert_eq!(cooldown(&page(article)), secs.map(Duration::from_secs));
+        }
+    }
+
+    #[test]
+    fn it_reads_verdicts() {
+        let cases = [
+            (
+                "That's the right answer! You are <span>one gold star</span> closer.",
+                Verdict::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.",
+                Verdict::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Verdict::TooLow,
+            ),
+            (
+                "That's not the right answer. If you're stuck...",
+                Verdict::Wrong,
+            ),
+            (
+                "You gave an answer too recently; you have to wait. You have 4m 32s left to wait.",
+                Verdict::Wait("4m 32s".to_string()),
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Verdict::AlreadySolved,
+            ),
+            (
+                "Something <em>else</em>",
+                Verdict::Unknown("Something else".to_string()),
+            ),
+        ];
+
+        for (article, verdict) in cases {
+            assert_eq!(Verdict::from_response(&page(article)), verdict);
+        }
+    }
+}
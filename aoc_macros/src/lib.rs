@@ -0,0 +1,88 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, Error, Expr, ExprLit, ItemFn, Lit, MetaNameValue, Token};
+
+// Registers a plain solver function as one part of a day:
+//
+//     #[aoc(year = 2023, day = 4, part = 1)]
+//     fn solve(input: &str) -> u32 { ... }
+//
+// The year can be left out for days of 2023.
+// The function is kept as is, so it can still be called and tested directly.
+// Its result is turned into an answer with aoc_common::IntoAnswer.
+#[proc_macro_attribute]
+pub fn aoc(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args =
+        parse_macro_input!(attr with Punctuated::<MetaNameValue, Token![,]>::parse_terminated);
+    let func = parse_macro_input!(item as ItemFn);
+
+    match expand(args, func) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn expand(
+    args: Punctuated<MetaNameValue, Token![,]>,
+    func: ItemFn,
+) -> Result<proc_macro2::TokenStream, Error> {
+    let mut year = None;
+    let mut day = None;
+    let mut part = None;
+
+    for arg in &args {
+        let value = match &arg.value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(n), ..
+            }) => n.base10_parse::<u16>()?,
+            other => return Err(Error::new_spanned(other, "expected a number")),
+        };
+
+        if arg.path.is_ident("year") && value >= 2015 {
+            year = Some(value);
+        } else if arg.path.is_ident("day") && (1..=25).contains(&value) {
+            day = Some(value as u8);
+        } else if arg.path.is_ident("part") && (1..=2).contains(&value) {
+            part = Some(value as u8);
+        } else {
+            return Err(Error::new_spanned(
+                arg,
+                "expected `year = 2015..`, `day = 1..=25` or `part = 1..=2`",
+            ));
+        }
+    }
+
+    let (Some(day), Some(part)) = (day, part) else {
+        return Err(Error::new_spanned(
+            &args,
+            "both `day` and `part` are required",
+        ));
+    };
+
+    if func.sig.inputs.len() != 1 {
+        return Err(Error::new_spanned(
+            &func.sig,
+            "solver functions take the puzzle input as their only argument",
+        ));
+    }
+
+    let name = &func.sig.ident;
+    let year = match year {
+        Some(year) => quote!(#year),
+        None => quote!(::aoc_common::DEFAULT_YEAR),
+    };
+
+    Ok(quote! {
+        #func
+
+        ::aoc_common::registry::inventory::submit! {
+            ::aoc_common::registry::Part {
+                year: #year,
+                day: #day,
+                part: #part,
+                solve: |input| ::aoc_common::IntoAnswer::into_answer(#name(input)),
+            }
+        }
+    })
+}
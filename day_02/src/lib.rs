@@ -0,0 +1,643 @@
+use aoc_common::parse::{offset_of, span_of};
+use aoc_common::{Answer, AocError, Solution};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Default, PartialEq)]
+pub struct CubeSet {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+impl FromStr for CubeSet {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = CubeSet::default();
+
+        // Errors name and point at the cube, count or color that's wrong
+        s.trim().split(',').try_for_each(|c| {
+            let cube = c.trim();
+            let (num, color) = cube.split_once(' ').ok_or_else(|| {
+                AocError::parse_at(
+                    format!("split cube count and color ({cube})"),
+                    span_of(s, cube),
+                )
+            })?;
+
+            let num = num.parse::<u32>().map_err(|e| {
+                AocError::parse_at(format!("parse cube count ({num}): {e}"), span_of(s, num))
+            })?;
+
+            let color = color.trim();
+            match color {
+                "red" => {
+                    set.red = num;
+                    Ok(())
+                }
+                "green" => {
+                    set.green = num;
+                    Ok(())
+                }
+                "blue" => {
+                    set.blue = num;
+                    Ok(())
+                }
+
+                _ => Err(AocError::parse_at(
+                    format!("unknown cube color ({color}), expected red, green or blue"),
+                    span_of(s, color),
+                )),
+            }
+        })?;
+
+        Ok(set)
+    }
+}
+
+impl CubeSet {
+    // The power of a set is the factor of its components
+    fn get_power(&self) -> u32 {
+        self.red * self.green * self.blue
+    }
+
+    fn total(&self) -> u32 {
+        self.red + self.green + self.blue
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParseMode {
+    // Bail on the first line that fails to parse
+    Strict,
+    // Skip lines that fail to parse, recording a warning for each
+    Lenient,
+    // Carry on past lines that fail to parse, then fail with all their errors
+    Collect,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParseWarning {
+    pub line: usize,
+    pub content: String,
+    pub error: AocError,
+}
+
+// A game record, with any indented continuation lines joined onto it
+struct Record {
+    // The (1-based) physical line it started on
+    line: usize,
+    text: String,
+    // Where each of the joined lines starts, in the text and in the input
+    starts: Vec<(usize, usize)>,
+}
+
+impl Record {
+    // Moves a parse error from the record to the physical lines of the input
+    fn locate(&self, e: AocError, input: &str) -> AocError {
+        let shift = match &e {
+            AocError::ParseAt { span, .. } => self
+                .starts
+                .iter()
+                .rev()
+                .find(|(text, _)| *text <= span.start)
+                .map_or(0, |(text, input)| input - text),
+            _ => 0,
+        };
+
+        e.at(0..self.text.len()).offset(shift).locate(input)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Game {
+    id: u32,
+    sets: Vec<CubeSet>,
+}
+
+impl FromStr for Game {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (id, game) = s
+            .trim()
+            .split_once(':')
+            .ok_or_else(|| AocError::parse_at("split game id", 0..s.len()))?;
+
+        let id = id
+            .strip_prefix("Game ")
+            .and_then(|s| s.parse::<u32>().ok())
+            .ok_or_else(|| AocError::parse_at(format!("parse game id ({id})"), span_of(s, id)))?;
+
+        let sets = game
+            .trim()
+            .split(';')
+            .map(|set| CubeSet::from_str(set).map_err(|e| e.offset(offset_of(s, set))))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Game { id, sets })
+    }
+}
+
+impl Game {
+    fn is_viable_with_set(&self, set: &CubeSet) -> bool {
+        // Check that all sets in the game does not pull more cubes that the provided config
+        self.sets
+            .iter()
+            .all(|s| s.red <= set.red && s.green <= set.green && s.blue <= set.blue)
+    }
+
+    // Merge indented continuation lines into the game record they belong to.
+    // Each record keeps the (1-based) physical line number it started on.
+    fn join_records(s: &str) -> Vec<Record> {
+        let mut records: Vec<Record> = Vec::new();
+
+        for (i, line) in s.lines().enumerate() {
+            let is_continuation = line.starts_with(char::is_whitespace) && !line.trim().is_empty();
+
+            match records.last_mut() {
+                Some(record) if is_continuation => {
+                    record.text.push(' ');
+                    record
+                        .starts
+                        .push((record.text.len(), offset_of(s, line.trim())));
+                    record.text.push_str(line.trim());
+                }
+                _ => records.push(Record {
+                    line: i + 1,
+                    text: line.to_owned(),
+                    starts: vec![(0, offset_of(s, line))],
+                }),
+            }
+        }
+
+        records
+    }
+
+    pub fn parse_all(s: &str, mode: ParseMode) -> Result<(Vec<Game>, Vec<ParseWarning>), AocError> {
+        let mut games = Vec::new();
+        let mut warnings = Vec::new();
+        let mut errors = Vec::new();
+
+        for record in Game::join_records(s) {
+            let game = Game::from_str(&record.text).map_err(|e| record.locate(e, s));
+
+            match (game, mode) {
+                (Ok(game), _) => games.push(game),
+                (Err(e), ParseMode::Strict) => return Err(e),
+                (Err(e), ParseMode::Lenient) => warnings.push(ParseWarning {
+                    line: record.line,
+                    content: record.text,
+                    error: e,
+                }),
+                (Err(e), ParseMode::Collect) => errors.push(e),
+            }
+        }
+
+        AocError::all(errors)?;
+        Ok((games, warnings))
+    }
+
+    fn find_viable_for_set<'a>(games: &'a [Game], set: &CubeSet) -> Vec<&'a Game> {
+        games.iter().filter(|g| g.is_viable_with_set(set)).collect()
+    }
+
+    fn sum_ids(games: &Vec<&Game>) -> u32 {
+        games.iter().map(|g| g.id).sum()
+    }
+
+    // Find the minimum possible number of cubes for a game
+    fn find_min_set(&self) -> CubeSet {
+        let mut set = CubeSet::default();
+
+        self.sets.iter().for_each(|s| {
+            set.red = set.red.max(s.red);
+            set.green = set.green.max(s.green);
+            set.blue = set.blue.max(s.blue);
+        });
+
+        set
+    }
+
+    // Find the bag with the fewest cubes in total that makes at least `k` games
+    // viable. Ties are broken by fewest red, then green, then blue cubes.
+    pub fn find_smallest_bag(games: &[Game], k: usize) -> Option<CubeSet> {
+        if k > games.len() {
+            return None;
+        }
+
+        let min_sets: Vec<CubeSet> = games.iter().map(Game::find_min_set).collect();
+
+        // An optimal bag never holds more of a color than some game requires,
+        // so only those counts (or none at all) need to be tried for red and green
+        let reds: BTreeSet<u32> = min_sets.iter().map(|s| s.red).chain([0]).collect();
+        let greens: BTreeSet<u32> = min_sets.iter().map(|s| s.green).chain([0]).collect();
+
+        let mut best: Option<CubeSet> = None;
+
+        for &red in &reds {
+            for &green in &greens {
+                let mut blues: Vec<u32> = min_sets
+                    .iter()
+                    .filter(|s| s.red <= red && s.green <= green)
+                    .map(|s| s.blue)
+                    .collect();
+
+                if blues.len() < k {
+                    continue;
+                }
+
+                // Enough blue cubes for the k least demanding of the remaining games
+                blues.sort_unstable();
+                let blue = match k {
+                    0 => 0,
+                    k => blues[k - 1],
+                };
+
+                let bag = CubeSet { red, green, blue };
+                let is_better = best.as_ref().is_none_or(|b| {
+                    (bag.total(), bag.red, bag.green) < (b.total(), b.red, b.green)
+                });
+
+                if is_better {
+                    best = Some(bag);
+                }
+            }
+        }
+
+        best
+    }
+
+    pub fn color_summary(games: &[Game]) -> ColorSummary {
+        ColorSummary {
+            red: ColorStats::from_games(games, |s| s.red),
+            green: ColorStats::from_games(games, |s| s.green),
+            blue: ColorStats::from_games(games, |s| s.blue),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct ColorStats {
+    // Cubes of this color drawn over all draws in all games
+    total: u32,
+    // The most cubes of this color seen in a single draw
+    max: u32,
+    // Number of games by how many cubes of this color they need at minimum
+    distribution: BTreeMap<u32, u32>,
+}
+
+impl ColorStats {
+    fn from_games(games: &[Game], color: fn(&CubeSet) -> u32) -> Self {
+        let mut stats = ColorStats::default();
+
+        games.iter().for_each(|g| {
+            g.sets.iter().map(color).for_each(|n| {
+                stats.total += n;
+                stats.max = stats.max.max(n);
+            });
+
+            *stats
+                .distribution
+                .entry(color(&g.find_min_set()))
+                .or_default() += 1;
+        });
+
+        stats
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct ColorSummary {
+    red: ColorStats,
+    green: ColorStats,
+    blue: ColorStats,
+}
+
+impl ColorSummary {
+    // The summary as one JSON object with a key per color
+    pub fn to_json(&self) -> Result<String, AocError> {
+        serde_json::to_string(self).map_err(|e| format!("export color summary: {e}").into())
+    }
+}
+
+impl fmt::Display for ColorSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, stats) in [
+            ("red", &self.red),
+            ("green", &self.green),
+            ("blue", &self.blue),
+        ] {
+            let distribution = stats
+                .distribution
+                .iter()
+                .map(|(n, games)| format!("{n}x{games}"))
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            writeln!(
+                f,
+                "{name}: {} drawn, max {} per draw, games by min needed: {distribution}",
+                stats.total, stats.max
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn part_1(games: &[Game]) -> u32 {
+    let viable = Game::find_viable_for_set(
+        games,
+        &CubeSet {
+            red: 12,
+            green: 13,
+            blue: 14,
+        },
+    );
+
+    Game::sum_ids(&viable)
+}
+
+pub fn part_2(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .map(|g| g.find_min_set())
+        .map(|s| s.get_power())
+        .sum()
+}
+
+pub struct Day02;
+
+aoc_common::register!(2023, 2, Day02);
+
+impl Solution for Day02 {
+    type Parsed = Vec<Game>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, AocError> {
+        Game::parse_all(input, ParseMode::Strict).map(|(games, _)| games)
+    }
+
+    fn part1(&self, games: &Self::Parsed) -> Result<Answer, AocError> {
+        Ok(part_1(games).into())
+    }
+
+    fn part2(&self, games: &Self::Parsed) -> Result<Answer, AocError> {
+        Ok(part_2(games).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::*;
+    use aoc_common::testing::reorder_lines;
+
+    const INPUT: &str = include_str!("./example.txt");
+
+    #[test]
+    fn it_parses_games() {
+        let games = Game::parse_all(INPUT, ParseMode::Strict).unwrap().0;
+
+        assert_eq!(games.len(), 5);
+        assert_eq!(games[2].id, 3);
+        assert_eq!(games[2].sets.len(), 3);
+    }
+
+    #[test]
+    fn it_points_at_the_bad_cube() {
+        assert_eq!(
+            CubeSet::from_str(" 3 blue, x red").unwrap_err(),
+            AocError::parse_at("parse cube count (x): invalid digit found in string", 9..10)
+        );
+        assert_eq!(
+            CubeSet::from_str(" 3 blue, 4").unwrap_err(),
+            AocError::parse_at("split cube count and color (4)", 9..10)
+        );
+    }
+
+    #[test]
+    fn it_fails_on_malformed_line_in_strict_mode() {
+        const INPUT: &str = "Game 1: 3 blue, 4 red\nGame two: 1 blue\nGame 3: 2 green";
+
+        let e = Game::parse_all(INPUT, ParseMode::Strict).unwrap_err();
+
+        assert_eq!(
+            e,
+            AocError::parse_at("parse game id (Game two)", 22..30).locate(INPUT)
+        );
+        assert_eq!(e.to_string(), "line 2, column 1: parse game id (Game two)");
+    }
+
+    #[test]
+    fn it_collects_every_malformed_line() {
+        const INPUT: &str =
+            "Game 1: 3 blue, 4 red\nGame two: 1 blue\nGame 3: 2 purple\nGame 4: 2 green";
+
+        assert_eq!(
+            Game::parse_all(INPUT, ParseMode::Collect).unwrap_err(),
+            AocError::Many(vec![
+                AocError::parse_at("parse game id (Game two)", 22..30).locate(INPUT),
+                AocError::parse_at(
+                    "unknown cube color (purple), expected red, green or blue",
+                    49..55
+                )
+                .locate(INPUT),
+            ])
+        );
+    }
+
+    #[test]
+    fn it_skips_malformed_lines_in_lenient_mode() {
+        const INPUT: &str =
+            "Game 1: 3 blue, 4 red\nGame two: 1 blue\nGame 3: 2 purple\nGame 4: 2 green";
+
+        let (games, warnings) = Game::parse_all(INPUT, ParseMode::Lenient).unwrap();
+
+        assert_eq!(games.iter().map(|g| g.id).collect::<Vec<_>>(), vec![1, 4]);
+        assert_eq!(
+            warnings,
+            vec![
+                ParseWarning {
+                    line: 2,
+                    content: String::from("Game two: 1 blue"),
+                    error: AocError::parse_at("parse game id (Game two)", 22..30).locate(INPUT),
+                },
+                ParseWarning {
+                    line: 3,
+                    content: String::from("Game 3: 2 purple"),
+                    error: AocError::parse_at(
+                        "unknown cube color (purple), expected red, green or blue",
+                        49..55
+                    )
+                    .locate(INPUT),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn it_joins_continuation_lines() {
+        const INPUT: &str =
+            "Game 1: 3 blue, 4 red;\n    1 red, 2 green,\n    6 blue; 2 green\nGame 2: 1 blue";
+
+        let (games, _) = Game::parse_all(INPUT, ParseMode::Strict).unwrap();
+
+        assert_eq!(games.len(), 2);
+        assert_eq!(games[0].sets.len(), 3);
+        assert_eq!(games[0].find_min_set().get_power(), 4 * 2 * 6);
+        assert_eq!(games[1].id, 2);
+    }
+
+    #[test]
+    fn it_reports_physical_line_of_joined_record() {
+        const INPUT: &str =
+            "Game 1: 3 blue,\n  4 red\nGame 2: 1 blue;\n  2 purple\nGame 3: 2 green";
+
+        let (games, warnings) = Game::parse_all(INPUT, ParseMode::Lenient).unwrap();
+
+        assert_eq!(games.iter().map(|g| g.id).collect::<Vec<_>>(), vec![1, 3]);
+        assert_eq!(
+            warnings,
+            vec![ParseWarning {
+                line: 3,
+                content: String::from("Game 2: 1 blue; 2 purple"),
+                error: AocError::parse_at(
+                    "unknown cube color (purple), expected red, green or blue",
+                    44..50
+                )
+                .locate(INPUT),
+            }]
+        );
+        // The color is on the continuation line, not the one the game starts on
+        assert!(warnings[0]
+            .error
+            .to_string()
+            .starts_with("line 4, column 5: unknown cube color (purple)"));
+    }
+
+    #[test]
+    fn it_summarizes_colors() {
+        let games = Game::parse_all(INPUT, ParseMode::Strict).unwrap().0;
+        let summary = Game::color_summary(&games);
+
+        assert_eq!(
+            summary.red,
+            ColorStats {
+                total: 61,
+                max: 20,
+                distribution: BTreeMap::from([(1, 1), (4, 1), (6, 1), (14, 1), (20, 1)]),
+            }
+        );
+        assert_eq!(
+            summary.green,
+            ColorStats {
+                total: 48,
+                max: 13,
+                distribution: BTreeMap::from([(2, 1), (3, 3), (13, 1)]),
+            }
+        );
+        assert_eq!(
+            summary.blue,
+            ColorStats {
+                total: 50,
+                max: 15,
+                distribution: BTreeMap::from([(2, 1), (4, 1), (6, 2), (15, 1)]),
+            }
+        );
+    }
+
+    #[test]
+    fn it_prints_color_summary() {
+        const INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green\nGame 2: 4 red";
+        let games = Game::parse_all(INPUT, ParseMode::Strict).unwrap().0;
+
+        assert_eq!(
+            Game::color_summary(&games).to_string(),
+            "red: 9 drawn, max 4 per draw, games by min needed: 4x2\n\
+             green: 2 drawn, max 2 per draw, games by min needed: 0x1 2x1\n\
+             blue: 3 drawn, max 3 per draw, games by min needed: 0x1 3x1\n"
+        );
+    }
+
+    #[test]
+    fn it_exports_color_summary_as_json() {
+        const INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green\nGame 2: 4 red";
+        let games = Game::parse_all(INPUT, ParseMode::Strict).unwrap().0;
+
+        assert_eq!(
+            Game::color_summary(&games).to_json().unwrap(),
+            "{\"red\":{\"total\":9,\"max\":4,\"distribution\":{\"4\":2}},\
+             \"green\":{\"total\":2,\"max\":2,\"distribution\":{\"0\":1,\"2\":1}},\
+             \"blue\":{\"total\":3,\"max\":3,\"distribution\":{\"0\":1,\"3\":1}}}"
+        );
+    }
+
+    #[test]
+    fn it_finds_smallest_bag() {
+        let games = Game::parse_all(INPUT, ParseMode::Strict).unwrap().0;
+
+        let bag = |red, green, blue| Some(CubeSet { red, green, blue });
+
+        assert_eq!(Game::find_smallest_bag(&games, 0), bag(0, 0, 0));
+        assert_eq!(Game::find_smallest_bag(&games, 1), bag(1, 3, 4));
+        // (4, 3, 6) and (6, 3, 4) both hold 13 cubes, fewer reds wins
+        assert_eq!(Game::find_smallest_bag(&games, 2), bag(4, 3, 6));
+        assert_eq!(Game::find_smallest_bag(&games, 5), bag(20, 13, 15));
+        assert_eq!(Game::find_smallest_bag(&games, 6), None);
+    }
+
+    #[test]
+    fn smallest_bag_makes_enough_games_viable() {
+        let games = Game::parse_all(INPUT, ParseMode::Strict).unwrap().0;
+
+        for k in 0..=games.len() {
+            let bag = Game::find_smallest_bag(&games, k).unwrap();
+            assert!(Game::find_viable_for_set(&games, &bag).len() >= k);
+        }
+    }
+
+    #[test]
+    fn it_solves_part_1_example() {
+        let games = Game::parse_all(INPUT, ParseMode::Strict).unwrap().0;
+        let viable = Game::find_viable_for_set(
+            &games,
+            &CubeSet {
+                red: 12,
+                green: 13,
+                blue: 14,
+            },
+        );
+
+        assert_eq!(Game::sum_ids(&viable), 8);
+    }
+
+    #[test]
+    fn it_solves_part_2_example() {
+        let games = Game::parse_all(INPUT, ParseMode::Strict).unwrap().0;
+        let power_sum: u32 = games.iter().map(|g| g.find_min_set().get_power()).sum();
+
+        assert_eq!(power_sum, 2286);
+    }
+
+    #[test]
+    fn answers_do_not_depend_on_game_order() {
+        let bag = CubeSet {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+
+        for reordered in reorder_lines(INPUT) {
+            let games = Game::parse_all(&reordered, ParseMode::Strict).unwrap().0;
+            let viable = Game::find_viable_for_set(&games, &bag);
+            let power_sum: u32 = games.iter().map(|g| g.find_min_set().get_power()).sum();
+
+            assert_eq!(Game::sum_ids(&viable), 8);
+            assert_eq!(power_sum, 2286);
+        }
+    }
+}
@@ -0,0 +1,556 @@
+use aoc_common::{Answer, AocError, Solution};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Location {
+    line: usize,
+    index: usize,
+}
+
+impl Location {
+    fn new(line: usize, index: usize) -> Self {
+        Location { line, index }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Number {
+    number: u32,
+    location: Location,
+}
+
+impl Number {
+    fn new(number: u32, line: usize, index: usize) -> Self {
+        Number {
+            number,
+            location: Location::new(line, index),
+        }
+    }
+
+    fn get_adjacent_locations(&self) -> Vec<Location> {
+        let len = (self.number as f32).log10() as i32;
+        let range = (self.location.index as i32 - 1)..=(self.location.index as i32 + len + 1);
+        let ln_over: Vec<(i32, i32)> = range
+            .clone()
+            .map(|i| (self.location.line as i32 - 1, i))
+            .collect();
+
+        let ln_same = vec![
+            (self.location.line as i32, self.location.index as i32 - 1),
+            (
+                self.location.line as i32,
+                self.location.index as i32 + len + 1,
+            ),
+        ];
+
+        let ln_next: Vec<(i32, i32)> = range.map(|i| (self.location.line as i32 + 1, i)).collect();
+
+        [ln_over, ln_same, ln_next]
+            .iter()
+            .flatten()
+            .filter(|(l, i)| *l >= 0 && *i >= 0)
+            .map(|(l, i)| Location::new(*l as usize, *i as usize))
+            .collect()
+    }
+}
+
+type Symbol = String;
+type SymbolTable = HashMap<Location, Symbol>;
+
+#[derive(Debug, PartialEq)]
+pub struct Schematic {
+    numbers: Vec<Number>,
+    symbols: SymbolTable,
+}
+
+impl FromStr for Schematic {
+    type Err = AocError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut schematic = Schematic {
+            numbers: vec![],
+            symbols: HashMap::new(),
+        };
+
+        s.lines().enumerate().for_each(|(li, l)| {
+            let mut num = 0;
+
+            for (i, c) in l.char_indices() {
+                if c.is_ascii_digit() {
+                    // While we're reading a number, construct the number
+                    num = num * 10 + c.to_digit(10).unwrap();
+                } else if num != 0 {
+                    // When we're done; push the number to the numbers vec
+                    let n_idx = i - ((num as f32).log10() as usize) - 1;
+                    schematic.numbers.push(Number::new(num, li, n_idx));
+                    num = 0;
+                }
+
+                if !c.is_ascii_digit() && c != '.' {
+                    schematic
+                        .symbols
+                        .insert(Location::new(li, i), c.to_string());
+                }
+            }
+
+            if num != 0 {
+                let ni = l.len() - ((num as f32).log10() as usize) - 1;
+                schematic.numbers.push(Number::new(num, li, ni));
+            }
+        });
+
+        Ok(schematic)
+    }
+}
+
+impl Schematic {
+    fn find_part_numbers(&self) -> Vec<u32> {
+        self.numbers
+            .iter()
+            .filter(|n| {
+                n.get_adjacent_locations()
+                    .iter()
+                    .any(|l| self.symbols.contains_key(l))
+            })
+            .map(|n| n.number)
+            .collect()
+    }
+
+    fn find_gear_ratios(&self) -> Vec<u32> {
+        self.symbols
+            .iter()
+            .filter(|(_, sym)| &sym[..] == "*")
+            .filter_map(|(loc, _)| {
+                let numbers = self.find_adjacent_numbers(loc);
+
+                if numbers.len() == 2 {
+                    return Some(numbers[0].number * numbers[1].number);
+                }
+
+                None
+            })
+            .collect()
+    }
+
+    fn find_adjacent_numbers(&self, loc: &Location) -> Vec<&Number> {
+        self.numbers
+            .iter()
+            .filter(|n| n.get_adjacent_locations().iter().any(|l| l == loc))
+            .collect()
+    }
+
+    // Render the `*` symbols and the numbers next to them as a GraphViz graph.
+    // Gears (exactly two adjacent numbers) are filled in.
+    pub fn gear_graph_dot(&self) -> String {
+        let mut stars: Vec<&Location> = self
+            .symbols
+            .iter()
+            .filter(|(_, sym)| &sym[..] == "*")
+            .map(|(loc, _)| loc)
+            .collect();
+        stars.sort();
+
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        let mut seen_numbers = Vec::new();
+
+        for loc in stars {
+            let numbers = self.find_adjacent_numbers(loc);
+            let style = if numbers.len() == 2 {
+                ", style=filled, fillcolor=gold"
+            } else {
+                ""
+            };
+
+            let star = format!("s_{}_{}", loc.line, loc.index);
+            nodes.push(format!("    {star} [label=\"*\", shape=box{style}];"));
+
+            for n in numbers {
+                let number = format!("n_{}_{}", n.location.line, n.location.index);
+                if !seen_numbers.contains(&n) {
+                    seen_numbers.push(n);
+                    nodes.push(format!("    {number} [label=\"{}\"];", n.number));
+                }
+                edges.push(format!("    {star} -- {number};"));
+            }
+        }
+
+        let mut dot = String::from("graph gears {\n");
+        nodes.iter().chain(edges.iter()).for_each(|l| {
+            dot.push_str(l);
+            dot.push('\n');
+        });
+        dot.push_str("}\n");
+
+        dot
+    }
+
+    pub fn symbol_stats(&self) -> SymbolStatsTable {
+        // Count the numbers touching each symbol location
+        let mut adjacent: HashMap<&Location, usize> = HashMap::new();
+        self.numbers.iter().for_each(|n| {
+            n.get_adjacent_locations().iter().for_each(|l| {
+                if let Some((loc, _)) = self.symbols.get_key_value(l) {
+                    *adjacent.entry(loc).or_default() += 1;
+                }
+            })
+        });
+
+        let mut table: BTreeMap<Symbol, SymbolStats> = BTreeMap::new();
+        self.symbols.iter().for_each(|(loc, sym)| {
+            let numbers = adjacent.get(loc).copied().unwrap_or_default();
+            let stats = table.entry(sym.clone()).or_default();
+
+            stats.count += 1;
+            stats.adjacent_numbers += numbers;
+            if numbers > 0 {
+                stats.adjacent += 1;
+            }
+        });
+
+        SymbolStatsTable(table)
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct SymbolStats {
+    // Occurrences of the symbol in the schematic
+    count: usize,
+    // Occurrences with at least one adjacent number
+    adjacent: usize,
+    // Adjacent numbers summed over all occurrences
+    adjacent_numbers: usize,
+}
+
+impl SymbolStats {
+    fn average_adjacent_numbers(&self) -> f32 {
+        self.adjacent_numbers as f32 / self.count as f32
+    }
+}
+
+pub struct SymbolStatsTable(BTreeMap<Symbol, SymbolStats>);
+
+impl fmt::Display for SymbolStatsTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (sym, stats) in &self.0 {
+            writeln!(
+                f,
+                "{sym}: {} found, {} next to a number, {:.2} numbers on average",
+                stats.count,
+                stats.adjacent,
+                stats.average_adjacent_numbers()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+// Items only in the old schematic, only in the new one, and items whose
+// value stayed the same but moved to another location
+#[derive(Debug, PartialEq)]
+struct Changes<T> {
+    added: Vec<T>,
+    removed: Vec<T>,
+    moved: Vec<(T, T)>,
+}
+
+impl<T: PartialEq + Copy> Changes<T> {
+    fn between<V: PartialEq>(old: &[T], new: &[T], value: fn(&T) -> V) -> Self {
+        let mut removed: Vec<T> = old.iter().filter(|o| !new.contains(o)).copied().collect();
+        let mut added: Vec<T> = new.iter().filter(|n| !old.contains(n)).copied().collect();
+        let mut moved = Vec::new();
+
+        // Pair up removed and added items with the same value as moves
+        removed.retain(|r| match added.iter().position(|a| value(a) == value(r)) {
+            Some(i) => {
+                moved.push((*r, added.remove(i)));
+                false
+            }
+            None => true,
+        });
+
+        Changes {
+            added,
+            removed,
+            moved,
+        }
+    }
+}
+
+type SymbolEntry<'a> = (&'a Location, &'a Symbol);
+
+#[derive(Debug, PartialEq)]
+pub struct SchematicDiff<'a> {
+    numbers: Changes<&'a Number>,
+    symbols: Changes<SymbolEntry<'a>>,
+    part_sum_change: i64,
+}
+
+impl Schematic {
+    pub fn diff<'a>(&'a self, other: &'a Schematic) -> SchematicDiff<'a> {
+        let numbers = Changes::between(
+            &self.numbers.iter().collect::<Vec<_>>(),
+            &other.numbers.iter().collect::<Vec<_>>(),
+            |n| n.number,
+        );
+
+        // Sort the symbols so the report doesn't depend on hash order
+        let sorted_symbols = |s: &'a Schematic| {
+            let mut symbols: Vec<SymbolEntry> = s.symbols.iter().collect();
+            symbols.sort();
+            symbols
+        };
+
+        let symbols =
+            Changes::between(&sorted_symbols(self), &sorted_symbols(other), |(_, sym)| {
+                sym.to_string()
+            });
+
+        let part_sum = |s: &Schematic| s.find_part_numbers().iter().map(|&n| n as i64).sum::<i64>();
+
+        SchematicDiff {
+            numbers,
+            symbols,
+            part_sum_change: part_sum(other) - part_sum(self),
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line + 1, self.index + 1)
+    }
+}
+
+impl fmt::Display for SchematicDiff<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for n in &self.numbers.removed {
+            writeln!(f, "- {} at {}", n.number, n.location)?;
+        }
+        for n in &self.numbers.added {
+            writeln!(f, "+ {} at {}", n.number, n.location)?;
+        }
+        for (from, to) in &self.numbers.moved {
+            writeln!(
+                f,
+                "~ {} moved {} -> {}",
+                from.number, from.location, to.location
+            )?;
+        }
+        for (loc, sym) in &self.symbols.removed {
+            writeln!(f, "- {sym} at {loc}")?;
+        }
+        for (loc, sym) in &self.symbols.added {
+            writeln!(f, "+ {sym} at {loc}")?;
+        }
+        for ((from, sym), (to, _)) in &self.symbols.moved {
+            writeln!(f, "~ {sym} moved {from} -> {to}")?;
+        }
+
+        writeln!(f, "Part number sum change: {:+}", self.part_sum_change)
+    }
+}
+
+pub fn part_1(schm: &Schematic) -> u32 {
+    schm.find_part_numbers().iter().sum()
+}
+
+pub fn part_2(schm: &Schematic) -> u32 {
+    schm.find_gear_ratios().iter().sum()
+}
+
+pub struct Day03;
+
+aoc_common::register!(2023, 3, Day03);
+
+impl Solution for Day03 {
+    type Parsed = Schematic;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, AocError> {
+        Schematic::from_str(input)
+    }
+
+    fn part1(&self, schm: &Self::Parsed) -> Result<Answer, AocError> {
+        Ok(part_1(schm).into())
+    }
+
+    fn part2(&self, schm: &Self::Parsed) -> Result<Answer, AocError> {
+        Ok(part_2(schm).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use crate::*;
+
+    #[test]
+    fn it_parses_numbers() {
+        const INPUT: &str = "...123..34..5..78";
+
+        let schm = Schematic::from_str(INPUT).unwrap();
+
+        let expect = vec![
+            Number::new(123, 0, 3),
+            Number::new(34, 0, 8),
+            Number::new(5, 0, 12),
+            Number::new(78, 0, 15),
+        ];
+
+        assert_eq!(schm.numbers, expect);
+    }
+
+    #[test]
+    fn it_parses_symbols() {
+        const INPUT: &str = "...*123..#.4$";
+
+        let schm = Schematic::from_str(INPUT).unwrap();
+
+        let mut expect = HashMap::new();
+        expect.insert(Location::new(0, 3), String::from("*"));
+        expect.insert(Location::new(0, 9), String::from("#"));
+        expect.insert(Location::new(0, 12), String::from("$"));
+
+        assert_eq!(schm.symbols, expect);
+    }
+
+    #[test]
+    fn it_parses_both() {
+        const INPUT: &str = "...*123..#.4$";
+
+        let schm = Schematic::from_str(INPUT).unwrap();
+
+        let expect_num = vec![Number::new(123, 0, 4), Number::new(4, 0, 11)];
+        let mut expect_sym = HashMap::new();
+        expect_sym.insert(Location::new(0, 3), String::from("*"));
+        expect_sym.insert(Location::new(0, 9), String::from("#"));
+        expect_sym.insert(Location::new(0, 12), String::from("$"));
+
+        let expect = Schematic {
+            numbers: expect_num,
+            symbols: expect_sym,
+        };
+
+        assert_eq!(schm, expect);
+    }
+
+    const INPUT: &str = include_str!("./example.txt");
+
+    #[test]
+    fn it_counts_symbol_stats() {
+        let stats = Schematic::from_str(INPUT).unwrap().symbol_stats().0;
+
+        assert_eq!(
+            stats.get("*"),
+            Some(&SymbolStats {
+                count: 3,
+                adjacent: 3,
+                adjacent_numbers: 5
+            })
+        );
+        assert_eq!(
+            stats.get("#"),
+            Some(&SymbolStats {
+                count: 1,
+                adjacent: 1,
+                adjacent_numbers: 1
+            })
+        );
+        assert_eq!(stats.keys().collect::<Vec<_>>(), vec!["#", "$", "*", "+"]);
+    }
+
+    #[test]
+    fn it_counts_lone_symbols() {
+        const INPUT: &str = "12....\n..*..*\n......";
+
+        let stats = Schematic::from_str(INPUT).unwrap().symbol_stats().0;
+        let star = stats.get("*").unwrap();
+
+        assert_eq!(star.count, 2);
+        assert_eq!(star.adjacent, 1);
+        assert_eq!(star.average_adjacent_numbers(), 0.5);
+    }
+
+    #[test]
+    fn it_diffs_identical_schematics() {
+        let a = Schematic::from_str(INPUT).unwrap();
+        let b = Schematic::from_str(INPUT).unwrap();
+
+        let diff = a.diff(&b);
+
+        assert!(diff.numbers.added.is_empty());
+        assert!(diff.numbers.removed.is_empty());
+        assert!(diff.numbers.moved.is_empty());
+        assert!(diff.symbols.added.is_empty());
+        assert_eq!(diff.part_sum_change, 0);
+    }
+
+    #[test]
+    fn it_diffs_schematics() {
+        let a = Schematic::from_str("467..114..\n...*......\n..35..633.").unwrap();
+        let b = Schematic::from_str("467...114.\n...*.....#\n..36..633.").unwrap();
+
+        let diff = a.diff(&b);
+
+        assert_eq!(diff.numbers.added, vec![&Number::new(36, 2, 2)]);
+        assert_eq!(diff.numbers.removed, vec![&Number::new(35, 2, 2)]);
+        assert_eq!(
+            diff.numbers.moved,
+            vec![(&Number::new(114, 0, 5), &Number::new(114, 0, 6))]
+        );
+        assert_eq!(
+            diff.symbols.added,
+            vec![(&Location::new(1, 9), &String::from("#"))]
+        );
+        assert!(diff.symbols.removed.is_empty());
+        // 35 -> 36 next to the *, and both 114 and 633 now touch the #
+        assert_eq!(diff.part_sum_change, 1 + 114 + 633);
+    }
+
+    #[test]
+    fn it_prints_diff() {
+        let a = Schematic::from_str("12.\n..*").unwrap();
+        let b = Schematic::from_str(".12\n*..").unwrap();
+
+        assert_eq!(
+            a.diff(&b).to_string(),
+            "~ 12 moved 1:1 -> 1:2\n~ * moved 2:3 -> 2:1\nPart number sum change: +0\n"
+        );
+    }
+
+    #[test]
+    fn it_exports_gear_graph() {
+        const INPUT: &str = "12*34\n.....\n5*..*";
+
+        let dot = Schematic::from_str(INPUT).unwrap().gear_graph_dot();
+
+        assert_eq!(
+            dot,
+            "graph gears {
+    s_0_2 [label=\"*\", shape=box, style=filled, fillcolor=gold];
+    n_0_0 [label=\"12\"];
+    n_0_3 [label=\"34\"];
+    s_2_1 [label=\"*\", shape=box];
+    n_2_0 [label=\"5\"];
+    s_2_4 [label=\"*\", shape=box];
+    s_0_2 -- n_0_0;
+    s_0_2 -- n_0_3;
+    s_2_1 -- n_2_0;
+}
+"
+        );
+    }
+
+    #[test]
+    fn it_solves_part_1() {
+        assert_eq!(part_1(&Schematic::from_str(INPUT).unwrap()), 4361);
+    }
+
+    #[test]
+    fn it_solves_part_2() {
+        assert_eq!(part_2(&Schematic::from_str(INPUT).unwrap()), 467835);
+    }
+}
@@ -0,0 +1,629 @@
+use aoc_common::parse::{offset_of, parse_numbers, span_of};
+use aoc_common::{Answer, AocError, Solution};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+use tracing::debug;
+
+#[derive(Debug, PartialEq)]
+pub struct CardNumber {
+    number: u32,
+    index: usize,
+}
+
+impl CardNumber {
+    fn new(number: u32, index: usize) -> Self {
+        CardNumber { number, index }
+    }
+
+    fn vec_from_str(s: &str) -> Result<Vec<Self>, AocError> {
+        Ok(parse_numbers::<u32>(s)?
+            .into_iter()
+            .enumerate()
+            .map(|(i, n)| CardNumber::new(n, i))
+            .collect())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Card {
+    number: u32,
+    winning_numbers: Vec<CardNumber>,
+    card_numbers: Vec<CardNumber>,
+}
+
+impl FromStr for Card {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Errors point at the part of the line that's wrong
+        let (title, numbers) = s
+            .split_once(":")
+            .ok_or_else(|| AocError::parse_at("split line", 0..s.len()))?;
+        let title = title.trim();
+        let (_, card_number) = title
+            .split_once(" ")
+            .ok_or_else(|| AocError::parse_at("split title", span_of(s, title)))?;
+
+        let card_number = card_number.trim();
+        let card_number = card_number.parse::<u32>().map_err(|e| {
+            AocError::parse_at(
+                format!("parse card number ({card_number}): {e}"),
+                span_of(s, card_number),
+            )
+        })?;
+
+        let numbers = numbers.trim();
+        let (winning_numbers, your_numbers) = numbers
+            .split_once("|")
+            .ok_or_else(|| AocError::parse_at("split numbers", span_of(s, numbers)))?;
+
+        let winning_numbers = CardNumber::vec_from_str(winning_numbers)
+            .map_err(|e| e.offset(offset_of(s, winning_numbers)))?;
+        let your_numbers = CardNumber::vec_from_str(your_numbers)
+            .map_err(|e| e.offset(offset_of(s, your_numbers)))?;
+
+        Ok(Card {
+            number: card_number,
+            winning_numbers,
+            card_numbers: your_numbers,
+        })
+    }
+}
+
+impl Card {
+    fn get_matching_numbers(&self) -> Vec<u32> {
+        let mut winning_numbers: HashSet<u32> = HashSet::new();
+        self.winning_numbers.iter().for_each(|n| {
+            winning_numbers.insert(n.number);
+        });
+
+        self.card_numbers
+            .iter()
+            .filter(|n| winning_numbers.contains(&n.number))
+            .map(|n| n.number)
+            .collect()
+    }
+
+    fn get_points(&self) -> u32 {
+        let mut winning_numbers: HashSet<u32> = HashSet::new();
+        self.winning_numbers.iter().for_each(|n| {
+            winning_numbers.insert(n.number);
+        });
+
+        let num_winning_numbers = self.get_matching_numbers().len();
+        let points = if num_winning_numbers > 0 {
+            2u32.pow(num_winning_numbers as u32 - 1u32)
+        } else {
+            0
+        };
+
+        debug!(
+            card = self.number,
+            matches = num_winning_numbers,
+            points,
+            "scored card"
+        );
+        points
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParseMode {
+    // Bail on the first card that fails to parse
+    Strict,
+    // Skip cards that fail to parse, recording a warning for each
+    Lenient,
+    // Carry on past cards that fail to parse, then fail with all their errors
+    Collect,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParseWarning {
+    pub line: usize,
+    pub content: String,
+    pub error: AocError,
+}
+
+pub fn parse_cards(s: &str, mode: ParseMode) -> Result<(Vec<Card>, Vec<ParseWarning>), AocError> {
+    let mut cards = Vec::new();
+    let mut warnings = Vec::new();
+    let mut errors = Vec::new();
+
+    for (i, line) in s.lines().enumerate() {
+        // Errors point into the whole input, not just the line
+        let card = Card::from_str(line)
+            .map_err(|e| e.at(0..line.len()).offset(offset_of(s, line)).locate(s));
+
+        match (card, mode) {
+            (Ok(card), _) => cards.push(card),
+            (Err(e), ParseMode::Strict) => return Err(e),
+            (Err(e), ParseMode::Lenient) => warnings.push(ParseWarning {
+                line: i + 1,
+                content: line.to_owned(),
+                error: e,
+            }),
+            (Err(e), ParseMode::Collect) => errors.push(e),
+        }
+    }
+
+    AocError::all(errors)?;
+    Ok((cards, warnings))
+}
+
+pub fn calculate_won_cards(cards: &[Card]) -> u32 {
+    let mut num_cards: HashMap<u32, u32> = HashMap::from_iter(cards.iter().map(|c| (c.number, 1)));
+
+    for card in cards {
+        let num_cards_won = card.get_matching_numbers().len() as u32;
+        let won_cards = (card.number + 1)..(card.number + 1 + num_cards_won);
+
+        let num_current_card = { *num_cards.get(&card.number).unwrap_or(&1) };
+        debug!(
+            card = card.number,
+            matches = num_cards_won,
+            copies = num_current_card,
+            "won cards"
+        );
+
+        for crd in won_cards {
+            let current_num = { num_cards.get(&crd).unwrap_or(&1) };
+            num_cards.insert(crd, *current_num + num_current_card);
+        }
+    }
+
+    num_cards.values().sum()
+}
+
+// Copies of a single card past which growth over rounds counts as runaway,
+// far more than part 2 wins on real inputs
+pub const DEFAULT_COPY_LIMIT: u64 = 1_000_000_000;
+
+// The total number of cards after each round simulated, and why the rounds
+// stopped early, if they did
+#[derive(Debug, PartialEq)]
+pub struct Rounds {
+    pub totals: Vec<u64>,
+    pub stopped: Option<AocError>,
+}
+
+// Repeat the part 2 cascade for a number of rounds, where every card starts a
+// round with the copies it ended the previous round with. Stops once any card's
+// copies would exceed `limit`, keeping the totals of the rounds before.
+pub fn simulate_rounds(cards: &[Card], rounds: usize, limit: u64) -> Rounds {
+    let matches: HashMap<u32, u32> = cards
+        .iter()
+        .map(|c| (c.number, c.get_matching_numbers().len() as u32))
+        .collect();
+    let mut copies: HashMap<u32, u64> = cards.iter().map(|c| (c.number, 1)).collect();
+    let mut totals = Vec::new();
+
+    for round in 1..=rounds {
+        match play_round(cards, &matches, &mut copies, limit) {
+            Ok(total) => totals.push(total),
+            Err(e) => {
+                return Rounds {
+                    totals,
+                    stopped: Some(format!("round {round}: {e}").into()),
+                }
+            }
+        }
+    }
+
+    Rounds {
+        totals,
+        stopped: None,
+    }
+}
+
+// One round of the cascade, returning the total number of cards after it
+fn play_round(
+    cards: &[Card],
+    matches: &HashMap<u32, u32>,
+    copies: &mut HashMap<u32, u64>,
+    limit: u64,
+) -> Result<u64, String> {
+    for card in cards {
+        let current = copies[&card.number];
+        let won_cards = (card.number + 1)..(card.number + 1 + matches[&card.number]);
+
+        for won in won_cards {
+            if let Some(count) = copies.get_mut(&won) {
+                *count = count
+                    .checked_add(current)
+                    .filter(|c| *c <= limit)
+                    .ok_or(format!("copies of card {won} exceed {limit}"))?;
+            }
+        }
+    }
+
+    copies
+        .values()
+        .try_fold(0u64, |acc, c| acc.checked_add(*c))
+        .ok_or("total number of cards overflows".to_string())
+}
+
+// Render the cards won by card `number` as a tree, recursing into the cards
+// those copies win in turn up to `max_depth` levels. Each card is annotated with
+// the number of copies won through it, and the copies won at every level are
+// summed up at the end.
+pub fn winnings_tree(cards: &[Card], number: u32, max_depth: usize) -> Result<String, AocError> {
+    let matches: HashMap<u32, Vec<u32>> = cards
+        .iter()
+        .map(|c| (c.number, c.get_matching_numbers()))
+        .collect();
+
+    let card = cards
+        .iter()
+        .find(|c| c.number == number)
+        .ok_or(format!("no card {number}"))?;
+
+    let won_by = |n: u32| -> Vec<u32> {
+        let won = matches.get(&n).map_or(0, |m| m.len() as u32);
+        ((n + 1)..(n + 1 + won))
+            .filter(|w| matches.contains_key(w))
+            .collect()
+    };
+
+    // Cards only win cards with higher numbers, so work backwards from the last
+    let mut numbers: Vec<u32> = matches.keys().copied().collect();
+    numbers.sort_unstable_by(|a, b| b.cmp(a));
+
+    let mut copies_won: HashMap<u32, u64> = HashMap::new();
+    for n in numbers {
+        let copies = won_by(n).iter().map(|w| 1 + copies_won[w]).sum();
+        copies_won.insert(n, copies);
+    }
+
+    fn render(
+        n: u32,
+        depth: usize,
+        max_depth: usize,
+        won_by: &dyn Fn(u32) -> Vec<u32>,
+        copies_won: &HashMap<u32, u64>,
+        out: &mut String,
+    ) {
+        for w in won_by(n) {
+            let more = if depth == max_depth && copies_won[&w] > 0 {
+                " ..."
+            } else {
+                ""
+            };
+            out.push_str(&format!(
+                "{}Card {w} (+{}){more}\n",
+                "  ".repeat(depth),
+                copies_won[&w]
+            ));
+
+            if depth < max_depth {
+                render(w, depth + 1, max_depth, won_by, copies_won, out);
+            }
+        }
+    }
+
+    let matching = matches[&number]
+        .iter()
+        .map(|m| m.to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut out = format!(
+        "Card {number}: {} points, matching [{matching}], wins {} copies\n",
+        card.get_points(),
+        copies_won[&number]
+    );
+    render(number, 1, max_depth, &won_by, &copies_won, &mut out);
+
+    let mut level: HashMap<u32, u64> = HashMap::from([(number, 1)]);
+    for depth in 1.. {
+        let mut next: HashMap<u32, u64> = HashMap::new();
+        level.iter().for_each(|(n, copies)| {
+            won_by(*n)
+                .iter()
+                .for_each(|w| *next.entry(*w).or_default() += copies)
+        });
+
+        if next.is_empty() {
+            break;
+        }
+
+        out.push_str(&format!(
+            "Level {depth}: {} copies\n",
+            next.values().sum::<u64>()
+        ));
+        level = next;
+    }
+
+    Ok(out)
+}
+
+pub fn part_1(cards: &[Card]) -> u32 {
+    cards.iter().map(Card::get_points).sum()
+}
+
+pub struct Day04;
+
+aoc_common::register!(2023, 4, Day04);
+
+impl Solution for Day04 {
+    type Parsed = Vec<Card>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, AocError> {
+        parse_cards(input, ParseMode::Strict).map(|(cards, _)| cards)
+    }
+
+    fn part1(&self, cards: &Self::Parsed) -> Result<Answer, AocError> {
+        Ok(part_1(cards).into())
+    }
+
+    fn part2(&self, cards: &Self::Parsed) -> Result<Answer, AocError> {
+        Ok(calculate_won_cards(cards).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::*;
+    use aoc_common::testing::reorder_lines;
+    use test_case::test_case;
+
+    #[test]
+    fn it_parses_line() {
+        const INPUT: &str = "Card 1: 1 2 3 | 3 4 5";
+
+        let expect = Card {
+            number: 1,
+            winning_numbers: vec![
+                CardNumber::new(1, 0),
+                CardNumber::new(2, 1),
+                CardNumber::new(3, 2),
+            ],
+            card_numbers: vec![
+                CardNumber::new(3, 0),
+                CardNumber::new(4, 1),
+                CardNumber::new(5, 2),
+            ],
+        };
+
+        assert_eq!(INPUT.parse::<Card>().unwrap(), expect);
+    }
+
+    #[test]
+    fn it_parses_line_with_multiple_whitespaces() {
+        const INPUT: &str = "Card 1: 1 2 3 | 12 13  4";
+
+        let expect = Card {
+            number: 1,
+            winning_numbers: vec![
+                CardNumber::new(1, 0),
+                CardNumber::new(2, 1),
+                CardNumber::new(3, 2),
+            ],
+            card_numbers: vec![
+                CardNumber::new(12, 0),
+                CardNumber::new(13, 1),
+                CardNumber::new(4, 2),
+            ],
+        };
+
+        assert_eq!(Card::from_str(INPUT), Ok(expect));
+    }
+
+    #[test_case(vec![1, 2], vec![0], 0)]
+    #[test_case(vec![1, 2], vec![1, 3, 4, 5], 1)]
+    #[test_case(vec![1, 2], vec![1, 2], 2)]
+    #[test_case(vec![1, 2], vec![1, 2, 2, 2, 3], 8)]
+    #[test_case(vec![41, 48, 83, 86, 17], vec![83, 86, 6, 31, 17, 9, 48, 53], 8)]
+    #[test_case(vec![13, 32, 20, 16, 61, ], vec![ 61, 30, 68, 82, 17, 32, 24, 19], 2)]
+    #[test_case(vec![87, 83, 26, 28, 32 ], vec![ 88, 30, 70, 12, 93, 22, 82, 36], 0)]
+    fn it_calculates_points(winning: Vec<u32>, nums: Vec<u32>, points: u32) {
+        let card = Card {
+            number: 0,
+            winning_numbers: winning
+                .iter()
+                .enumerate()
+                .map(|(i, n)| CardNumber::new(*n, i))
+                .collect(),
+            card_numbers: nums
+                .iter()
+                .enumerate()
+                .map(|(i, n)| CardNumber::new(*n, i))
+                .collect(),
+        };
+
+        assert_eq!(card.get_points(), points)
+    }
+
+    #[test]
+    fn it_fails_on_malformed_card_in_strict_mode() {
+        const INPUT: &str = "Card 1: 1 2 | 3 4\nCard 2: 1 x | 3 4";
+
+        let e = parse_cards(INPUT, ParseMode::Strict).unwrap_err();
+
+        assert_eq!(
+            e,
+            AocError::parse_at("parse number (x): invalid digit found in string", 28..29)
+                .locate(INPUT)
+        );
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 11: parse number (x): invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn it_skips_malformed_cards_in_lenient_mode() {
+        const INPUT: &str = "Card 1: 1 2 | 3 4\nCard 2: 1 2 3 4\nCard 3: 5 | 5";
+
+        let (cards, warnings) = parse_cards(INPUT, ParseMode::Lenient).unwrap();
+
+        assert_eq!(
+            cards.iter().map(|c| c.number).collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+        assert_eq!(
+            warnings,
+            vec![ParseWarning {
+                line: 2,
+                content: String::from("Card 2: 1 2 3 4"),
+                error: AocError::parse_at("split numbers", 26..33).locate(INPUT),
+            }]
+        );
+    }
+
+    #[test]
+    fn it_collects_every_malformed_card() {
+        const INPUT: &str = "Card 1: 1 x | 3 4\nCard 2: 1 2 | 3 4\nCard 3: 5 5";
+
+        assert_eq!(
+            parse_cards(INPUT, ParseMode::Collect).unwrap_err(),
+            AocError::Many(vec![
+                AocError::parse_at("parse number (x): invalid digit found in string", 10..11)
+                    .locate(INPUT),
+                AocError::parse_at("split numbers", 44..47).locate(INPUT),
+            ])
+        );
+        assert_eq!(
+            parse_cards("Card 1: 1 | 1", ParseMode::Collect)
+                .unwrap()
+                .0
+                .len(),
+            1
+        );
+    }
+
+    const EXAMPLE_INPUT: &str = include_str!("./example.txt");
+
+    #[test]
+    fn it_passes_part_1_example() {
+        let cards = EXAMPLE_INPUT
+            .lines()
+            .map(Card::from_str)
+            .collect::<Result<Vec<Card>, AocError>>()
+            .unwrap();
+        assert_eq!(cards.iter().map(Card::get_points).sum::<u32>(), 13);
+    }
+
+    #[test]
+    fn it_passes_part_2_example() {
+        let cards = EXAMPLE_INPUT
+            .lines()
+            .map(Card::from_str)
+            .collect::<Result<Vec<Card>, AocError>>()
+            .unwrap();
+
+        assert_eq!(calculate_won_cards(&cards), 30);
+    }
+
+    #[test]
+    fn it_renders_winnings_tree() {
+        let cards = EXAMPLE_INPUT
+            .lines()
+            .map(Card::from_str)
+            .collect::<Result<Vec<Card>, AocError>>()
+            .unwrap();
+
+        assert_eq!(
+            winnings_tree(&cards, 2, 2).unwrap(),
+            "Card 2: 2 points, matching [61 32], wins 6 copies
+  Card 3 (+3)
+    Card 4 (+1) ...
+    Card 5 (+0)
+  Card 4 (+1)
+    Card 5 (+0)
+Level 1: 2 copies
+Level 2: 3 copies
+Level 3: 1 copies
+"
+        );
+    }
+
+    #[test]
+    fn winnings_tree_agrees_with_part_2() {
+        let cards = EXAMPLE_INPUT
+            .lines()
+            .map(Card::from_str)
+            .collect::<Result<Vec<Card>, AocError>>()
+            .unwrap();
+
+        // Every original card ends up as itself plus all the copies it wins
+        let total: u64 = cards
+            .iter()
+            .map(|c| {
+                let tree = winnings_tree(&cards, c.number, 0).unwrap();
+                let (_, copies) = tree.lines().next().unwrap().rsplit_once("wins ").unwrap();
+                1 + copies.trim_end_matches(" copies").parse::<u64>().unwrap()
+            })
+            .sum();
+
+        assert_eq!(total, calculate_won_cards(&cards) as u64);
+    }
+
+    #[test]
+    fn winnings_tree_rejects_unknown_card() {
+        let cards = EXAMPLE_INPUT
+            .lines()
+            .map(Card::from_str)
+            .collect::<Result<Vec<Card>, AocError>>()
+            .unwrap();
+
+        assert_eq!(
+            winnings_tree(&cards, 7, 1),
+            Err(AocError::from("no card 7"))
+        );
+    }
+
+    #[test]
+    fn it_simulates_rounds() {
+        let cards = EXAMPLE_INPUT
+            .lines()
+            .map(Card::from_str)
+            .collect::<Result<Vec<Card>, AocError>>()
+            .unwrap();
+
+        assert_eq!(
+            simulate_rounds(&cards, 2, u64::MAX),
+            Rounds {
+                totals: vec![30, 76],
+                stopped: None
+            }
+        );
+    }
+
+    #[test]
+    fn it_stops_rounds_on_unbounded_growth() {
+        let cards = EXAMPLE_INPUT
+            .lines()
+            .map(Card::from_str)
+            .collect::<Result<Vec<Card>, AocError>>()
+            .unwrap();
+
+        // The rounds that completed are kept
+        assert_eq!(
+            simulate_rounds(&cards, 2, 40),
+            Rounds {
+                totals: vec![30],
+                stopped: Some(AocError::from("round 2: copies of card 5 exceed 40"))
+            }
+        );
+        assert_eq!(
+            simulate_rounds(&cards, 5, 100),
+            Rounds {
+                totals: vec![30, 76, 152],
+                stopped: Some(AocError::from("round 4: copies of card 5 exceed 100"))
+            }
+        );
+    }
+
+    // Part 2 walks the cards in order, so only part 1 is expected to hold
+    #[test]
+    fn part_1_does_not_depend_on_card_order() {
+        for reordered in reorder_lines(EXAMPLE_INPUT) {
+            let (cards, _) = parse_cards(&reordered, ParseMode::Strict).unwrap();
+
+            assert_eq!(cards.iter().map(Card::get_points).sum::<u32>(), 13);
+        }
+    }
+}
@@ -0,0 +1,775 @@
+use aoc_common::input::normalize_newlines;
+use aoc_common::parse::{offset_of, parse_numbers, span_of};
+use aoc_common::{Answer, AocError, Solution};
+use std::ops::Range;
+use std::str::FromStr;
+
+pub type Number = u64;
+
+#[derive(Debug, PartialEq)]
+pub struct Seeds(Vec<Number>);
+
+impl FromStr for Seeds {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_, seeds) = s
+            .split_once(":")
+            .ok_or_else(|| AocError::parse_at("split seed line", 0..s.len()))?;
+        Ok(Seeds(
+            parse_numbers(seeds).map_err(|e| e.offset(offset_of(s, seeds)))?,
+        ))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Mapping {
+    dest: Range<Number>,
+    src: Range<Number>,
+}
+
+impl FromStr for Mapping {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let nums = parse_numbers::<Number>(s)?;
+
+        if nums.len() != 3 {
+            return Err(AocError::parse_at("too few numbers in mapping", 0..s.len()));
+        }
+
+        Ok(Mapping::new(nums[0], nums[1], nums[2]))
+    }
+}
+
+impl Mapping {
+    fn new(dst: Number, src: Number, len: Number) -> Mapping {
+        Mapping {
+            dest: dst..(dst + len),
+            src: src..(src + len),
+        }
+    }
+
+    fn map(&self, n: Number) -> Option<Number> {
+        if self.src.contains(&n) {
+            let offset = n - self.src.start;
+            Some(self.dest.start + offset)
+        } else {
+            None
+        }
+    }
+}
+
+// Remove `cut` from each of the ranges, splitting them where needed
+fn subtract_range(ranges: Vec<Range<Number>>, cut: &Range<Number>) -> Vec<Range<Number>> {
+    ranges
+        .into_iter()
+        .flat_map(|r| {
+            [r.start..r.end.min(cut.start), r.start.max(cut.end)..r.end]
+                .into_iter()
+                .filter(|r| !r.is_empty())
+        })
+        .collect()
+}
+
+// Sort the ranges and join the ones that overlap or touch
+fn normalize_ranges(mut ranges: Vec<Range<Number>>) -> Vec<Range<Number>> {
+    ranges.retain(|r| !r.is_empty());
+    ranges.sort_by_key(|r| r.start);
+
+    let mut merged: Vec<Range<Number>> = Vec::new();
+    for r in ranges {
+        match merged.last_mut() {
+            Some(last) if r.start <= last.end => last.end = last.end.max(r.end),
+            _ => merged.push(r),
+        }
+    }
+
+    merged
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MappingTable {
+    from_label: String,
+    to_label: String,
+    mappings: Vec<Mapping>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParseMode {
+    // Bail on the first line that fails to parse
+    Strict,
+    // Carry on past lines that fail to parse, then fail with all their errors
+    Collect,
+}
+
+impl FromStr for MappingTable {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MappingTable::parse(s, ParseMode::Strict)
+    }
+}
+
+impl MappingTable {
+    // A table without its header can't be made sense of, but its mappings
+    // can be checked line by line
+    fn parse(s: &str, mode: ParseMode) -> Result<MappingTable, AocError> {
+        let lines = s.lines();
+
+        let header = s
+            .lines()
+            .take(1)
+            .reduce(|_, l| l)
+            .ok_or_else(|| AocError::parse_at("getting header line", 0..0))?;
+
+        let (from, to) = header
+            .split_once("-to-")
+            .ok_or_else(|| AocError::parse_at("split header", span_of(s, header)))?;
+        let (to, _) = to
+            .split_once(" ")
+            .ok_or_else(|| AocError::parse_at("split header to-part", span_of(s, to)))?;
+
+        let mut mappings = Vec::new();
+        let mut errors = Vec::new();
+        for l in lines.skip(1) {
+            match (l.parse::<Mapping>(), mode) {
+                (Ok(mapping), _) => mappings.push(mapping),
+                (Err(e), ParseMode::Strict) => return Err(e.offset(offset_of(s, l))),
+                (Err(e), ParseMode::Collect) => errors.push(e.offset(offset_of(s, l))),
+            }
+        }
+        AocError::all(errors)?;
+
+        Ok(MappingTable {
+            from_label: from.to_owned(),
+            to_label: to.to_owned(),
+            mappings,
+        })
+    }
+
+    fn map(&self, n: Number) -> Number {
+        for mapping in &self.mappings {
+            if let Some(res) = mapping.map(n) {
+                return res;
+            }
+        }
+
+        n
+    }
+
+    // Map a whole range through the table, splitting it wherever another
+    // mapping takes over. Like `map`, the first mapping covering a number wins.
+    fn map_range(&self, range: &Range<Number>) -> Vec<Range<Number>> {
+        let mut unmapped = vec![range.clone()];
+        let mut ranges = Vec::new();
+
+        for mapping in &self.mappings {
+            for r in &unmapped {
+                let start = r.start.max(mapping.src.start);
+                let end = r.end.min(mapping.src.end);
+                if start < end {
+                    ranges.push(
+                        (start - mapping.src.start + mapping.dest.start)
+                            ..(end - mapping.src.start + mapping.dest.start),
+                    );
+                }
+            }
+            unmapped = subtract_range(unmapped, &mapping.src);
+        }
+
+        // Numbers not covered by any mapping map onto themselves
+        ranges.extend(unmapped);
+
+        normalize_ranges(ranges)
+    }
+
+    // Find every input range that this table maps into `range`
+    fn preimage(&self, range: &Range<Number>) -> Vec<Range<Number>> {
+        let mut ranges = Vec::new();
+
+        for (i, mapping) in self.mappings.iter().enumerate() {
+            let start = mapping.dest.start.max(range.start);
+            let end = mapping.dest.end.min(range.end);
+            if start >= end {
+                continue;
+            }
+
+            let src = (start - mapping.dest.start + mapping.src.start)
+                ..(end - mapping.dest.start + mapping.src.start);
+
+            // Earlier mappings take precedence where sources overlap
+            let src = self.mappings[..i]
+                .iter()
+                .fold(vec![src], |acc, m| subtract_range(acc, &m.src));
+            ranges.extend(src);
+        }
+
+        // Numbers not covered by any mapping map onto themselves
+        let unmapped = self
+            .mappings
+            .iter()
+            .fold(vec![range.clone()], |acc, m| subtract_range(acc, &m.src));
+        ranges.extend(unmapped);
+
+        normalize_ranges(ranges)
+    }
+
+    // Merge another table over the same categories into this one. Mappings
+    // from this table come first, so they win wherever the two overlap.
+    fn merge(&mut self, other: MappingTable) -> Result<(), AocError> {
+        if self.from_label != other.from_label || self.to_label != other.to_label {
+            return Err(format!(
+                "merge {}-to-{} with {}-to-{}",
+                self.from_label, self.to_label, other.from_label, other.to_label
+            )
+            .into());
+        }
+
+        self.mappings.extend(other.mappings);
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Almanac {
+    seeds: Seeds,
+    mapping_tables: Vec<MappingTable>,
+}
+
+impl FromStr for Almanac {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Almanac::parse(s, ParseMode::Strict)
+    }
+}
+
+impl Almanac {
+    pub fn parse(s: &str, mode: ParseMode) -> Result<Almanac, AocError> {
+        let s = normalize_newlines(s);
+        let mut sections = s.split("\n\n");
+
+        // Errors point into the whole almanac, not just their section
+        let mut errors = Vec::new();
+
+        let seeds_line = sections
+            .next()
+            .ok_or_else(|| AocError::parse_at("empty almanac", 0..0))?;
+        let seeds = match (seeds_line.parse::<Seeds>(), mode) {
+            (Ok(seeds), _) => seeds,
+            (Err(e), ParseMode::Strict) => return Err(e.locate(&s)),
+            (Err(e), ParseMode::Collect) => {
+                errors.push(e);
+                Seeds(Vec::new())
+            }
+        };
+
+        let mut mapping_tables = Vec::new();
+        for sec in sections {
+            match (MappingTable::parse(sec, mode), mode) {
+                (Ok(table), _) => mapping_tables.push(table),
+                (Err(e), ParseMode::Strict) => return Err(e.offset(offset_of(&s, sec)).locate(&s)),
+                (Err(e), ParseMode::Collect) => errors.push(e.offset(offset_of(&s, sec))),
+            }
+        }
+        AocError::all(errors).map_err(|e| e.locate(&s))?;
+
+        Ok(Almanac {
+            seeds,
+            mapping_tables,
+        })
+    }
+
+    // Append the tables of another almanac, which has to pick up at the category
+    // this one ends with. The seeds of the other almanac are dropped.
+    pub fn chain(mut self, other: Almanac) -> Result<Almanac, AocError> {
+        if let (Some(last), Some(first)) =
+            (self.mapping_tables.last(), other.mapping_tables.first())
+        {
+            if last.to_label != first.from_label {
+                return Err(format!(
+                    "chain almanac ending in {} with one starting at {}",
+                    last.to_label, first.from_label
+                )
+                .into());
+            }
+        }
+
+        self.mapping_tables.extend(other.mapping_tables);
+        Ok(self)
+    }
+
+    // Pull `range` backwards through every table to find the seed ranges that
+    // end up inside it
+    pub fn seed_ranges_for(&self, range: Range<Number>) -> Vec<Range<Number>> {
+        self.mapping_tables
+            .iter()
+            .rev()
+            .fold(vec![range], |ranges, table| {
+                normalize_ranges(ranges.iter().flat_map(|r| table.preimage(r)).collect())
+            })
+    }
+
+    // Merge each table of another almanac into the table over the same
+    // categories in this one. The seeds of the other almanac are dropped.
+    pub fn merge(mut self, other: Almanac) -> Result<Almanac, AocError> {
+        for table in other.mapping_tables {
+            self.mapping_tables
+                .iter_mut()
+                .find(|t| t.from_label == table.from_label && t.to_label == table.to_label)
+                .ok_or(format!(
+                    "no {}-to-{} table to merge into",
+                    table.from_label, table.to_label
+                ))?
+                .merge(table)?;
+        }
+
+        Ok(self)
+    }
+
+    fn get_mapped_seeds(&self) -> Vec<Number> {
+        self.seeds
+            .0
+            .iter()
+            .map(|seed| {
+                self.mapping_tables
+                    .iter()
+                    .fold(*seed, |s, table| table.map(s))
+            })
+            .collect::<Vec<Number>>()
+    }
+
+    // For part 2 the seeds are pairs of the start and length of a range
+    fn seed_ranges(&self) -> Vec<Range<Number>> {
+        self.seeds
+            .0
+            .chunks_exact(2)
+            .map(|pair| pair[0]..(pair[0] + pair[1]))
+            .collect()
+    }
+
+    // Push the seed ranges through every table, splitting them on the way,
+    // to the location ranges they end up in
+    fn get_mapped_seed_ranges(&self) -> Vec<Range<Number>> {
+        self.mapping_tables
+            .iter()
+            .fold(normalize_ranges(self.seed_ranges()), |ranges, table| {
+                normalize_ranges(ranges.iter().flat_map(|r| table.map_range(r)).collect())
+            })
+    }
+}
+
+// The lowest location any of the seeds map to
+pub fn part_1(almanac: &Almanac) -> Option<Number> {
+    almanac.get_mapped_seeds().into_iter().min()
+}
+
+// The lowest location any seed in the seed ranges maps to. The ranges are
+// sorted, so it's where the first of them starts.
+pub fn part_2(almanac: &Almanac) -> Option<Number> {
+    almanac.get_mapped_seed_ranges().first().map(|r| r.start)
+}
+
+pub struct Day05;
+
+aoc_common::register!(2023, 5, Day05);
+
+impl Solution for Day05 {
+    type Parsed = Almanac;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, AocError> {
+        input.parse::<Almanac>()
+    }
+
+    fn part1(&self, almanac: &Self::Parsed) -> Result<Answer, AocError> {
+        part_1(almanac)
+            .map(Answer::from)
+            .ok_or(AocError::from("no seeds in almanac"))
+    }
+
+    fn part2(&self, almanac: &Self::Parsed) -> Result<Answer, AocError> {
+        part_2(almanac)
+            .map(Answer::from)
+            .ok_or(AocError::from("no seed ranges in almanac"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::*;
+    use test_case::test_case;
+
+    #[test]
+    fn it_parses_mapping() {
+        assert_eq!(
+            Mapping::from_str("2 4 2"),
+            Ok(Mapping {
+                dest: 2..4,
+                src: 4..6
+            })
+        )
+    }
+
+    #[test_case((50, 98, 2), 98, Some(50))]
+    #[test_case((50, 98, 2), 99, Some(51))]
+    #[test_case((50, 98, 2), 100, None)]
+    #[test_case((50, 98, 2), 17, None)]
+    #[test_case((50, 98, 0), 98, None)]
+    fn it_maps_correctly(
+        (dst, src, len): (Number, Number, Number),
+        from: Number,
+        to: Option<Number>,
+    ) {
+        let mapping = Mapping::new(dst, src, len);
+
+        assert_eq!(mapping.map(from), to);
+    }
+
+    #[test]
+    fn it_constructs_mapping_table() {
+        const INPUT: &str = "seed-to-soil map:\n50 98 2\n52 50 48";
+
+        let table = MappingTable {
+            from_label: String::from("seed"),
+            to_label: String::from("soil"),
+            mappings: vec![Mapping::new(50, 98, 2), Mapping::new(52, 50, 48)],
+        };
+
+        assert_eq!(MappingTable::from_str(INPUT), Ok(table));
+    }
+
+    #[test_case(98, 50)]
+    #[test_case(56, 58)]
+    #[test_case(17, 17)]
+    fn it_maps_with_table(from: Number, to: Number) {
+        const INPUT: &str = "seed-to-soil map:\n50 98 2\n52 50 48";
+
+        let table = MappingTable::from_str(INPUT).unwrap();
+
+        assert_eq!(table.map(from), to);
+    }
+
+    #[test]
+    fn it_solves_part_1_example() {
+        const INPUT: &str = include_str!("./example.txt");
+
+        let almanac = INPUT.parse::<Almanac>().unwrap();
+        let locations = almanac.get_mapped_seeds();
+
+        assert_eq!(*locations.iter().min().unwrap(), 35);
+    }
+
+    #[test]
+    fn it_solves_part_2_example() {
+        const INPUT: &str = include_str!("./example.txt");
+
+        let almanac = INPUT.parse::<Almanac>().unwrap();
+
+        assert_eq!(almanac.seed_ranges(), vec![79..93, 55..68]);
+        assert_eq!(part_2(&almanac), Some(46));
+    }
+
+    #[test]
+    fn it_maps_ranges_with_table() {
+        const INPUT: &str = "seed-to-soil map:\n50 98 2\n52 50 48";
+
+        let table = MappingTable::from_str(INPUT).unwrap();
+
+        assert_eq!(table.map_range(&(45..55)), vec![45..50, 52..57]);
+        // 97 maps to 99, next to 100 which maps onto itself
+        assert_eq!(table.map_range(&(97..101)), vec![50..52, 99..101]);
+        assert_eq!(table.map_range(&(0..10)), vec![0..10]);
+    }
+
+    #[test]
+    fn it_subtracts_ranges() {
+        let range = 0..10;
+
+        assert_eq!(
+            subtract_range(vec![range.clone()], &(3..5)),
+            vec![0..3, 5..10]
+        );
+        assert_eq!(subtract_range(vec![range.clone()], &(0..10)), vec![]);
+        assert_eq!(subtract_range(vec![range.clone()], &(8..20)), vec![0..8]);
+        assert_eq!(subtract_range(vec![range.clone()], &(20..30)), vec![range]);
+    }
+
+    #[test]
+    fn it_normalizes_ranges() {
+        assert_eq!(
+            normalize_ranges(vec![5..7, 0..3, 3..4, 6..9, 10..10]),
+            vec![0..4, 5..9]
+        );
+    }
+
+    #[test]
+    fn it_finds_table_preimage() {
+        const INPUT: &str = "seed-to-soil map:\n50 98 2\n52 50 48";
+
+        let table = MappingTable::from_str(INPUT).unwrap();
+
+        // 50 and 51 come from 98 and 99, 52 and 53 from 50 and 51, and nothing
+        // maps to 98 or 99 itself
+        assert_eq!(table.preimage(&(50..54)), vec![50..52, 98..100]);
+        assert_eq!(table.preimage(&(97..101)), vec![95..98, 100..101]);
+        assert_eq!(table.preimage(&(0..10)), vec![0..10]);
+    }
+
+    #[test]
+    fn it_finds_seed_ranges_for_locations() {
+        const INPUT: &str = include_str!("./example.txt");
+
+        let almanac = INPUT.parse::<Almanac>().unwrap();
+
+        for range in [35..36, 0..10, 40..90, 82..83] {
+            let seed_ranges = almanac.seed_ranges_for(range.clone());
+
+            for seed in 0..120 {
+                let location = almanac
+                    .mapping_tables
+                    .iter()
+                    .fold(seed, |s, table| table.map(s));
+
+                assert_eq!(
+                    seed_ranges.iter().any(|r| r.contains(&seed)),
+                    range.contains(&location),
+                    "seed {seed} -> location {location}, range {range:?}"
+                );
+            }
+        }
+
+        assert!(almanac
+            .seed_ranges_for(35..36)
+            .iter()
+            .any(|r| r.contains(&13)));
+    }
+
+    #[test]
+    fn it_merges_tables() {
+        let a = MappingTable::from_str("seed-to-soil map:\n50 98 2").unwrap();
+        let b = MappingTable::from_str("seed-to-soil map:\n52 50 48\n0 98 1").unwrap();
+
+        let mut table = a;
+        table.merge(b).unwrap();
+
+        assert_eq!(table.mappings.len(), 3);
+        // Overlapping ranges resolve to the first table
+        assert_eq!(table.map(98), 50);
+        assert_eq!(table.map(56), 58);
+    }
+
+    #[test]
+    fn it_points_at_malformed_mappings() {
+        const INPUT: &str = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 x 48";
+
+        let e = INPUT.parse::<Almanac>().unwrap_err();
+
+        assert_eq!(
+            e,
+            AocError::parse_at("parse number (x): invalid digit found in string", 43..44)
+                .locate(INPUT)
+        );
+        assert_eq!(
+            e.to_string(),
+            "line 5, column 4: parse number (x): invalid digit found in string"
+        );
+        assert_eq!(
+            "seeds 79".parse::<Almanac>().unwrap_err().to_string(),
+            "line 1, column 1: split seed line"
+        );
+    }
+
+    #[test]
+    fn it_collects_every_malformed_line() {
+        const INPUT: &str =
+            "seeds 79 14\n\nseed-to-soil map:\n50 98\n52 50 48\n\nsoil-to-water map:\n1 x 3";
+
+        assert_eq!(
+            Almanac::parse(INPUT, ParseMode::Collect).unwrap_err(),
+            AocError::Many(vec![
+                AocError::parse_at("split seed line", 0..11).locate(INPUT),
+                AocError::parse_at("too few numbers in mapping", 31..36).locate(INPUT),
+                AocError::parse_at("parse number (x): invalid digit found in string", 68..69)
+                    .locate(INPUT),
+            ])
+        );
+    }
+
+    #[test]
+    fn it_refuses_to_merge_different_categories() {
+        let mut a = MappingTable::from_str("seed-to-soil map:\n50 98 2").unwrap();
+        let b = MappingTable::from_str("soil-to-water map:\n52 50 48").unwrap();
+
+        assert_eq!(
+            a.merge(b),
+            Err(AocError::from("merge seed-to-soil with soil-to-water"))
+        );
+    }
+
+    #[test]
+    fn it_chains_almanacs() {
+        const INPUT: &str = include_str!("./example.txt");
+        let (head, tail) = INPUT.split_at(INPUT.find("\n\nwater-to-light").unwrap());
+
+        let head = head.parse::<Almanac>().unwrap();
+        let tail = format!("seeds: 1{tail}").parse::<Almanac>().unwrap();
+
+        let almanac = head.chain(tail).unwrap();
+
+        assert_eq!(almanac, INPUT.parse::<Almanac>().unwrap());
+    }
+
+    #[test]
+    fn it_refuses_to_chain_disconnected_almanacs() {
+        let a = "seeds: 1\n\nseed-to-soil map:\n1 2 3"
+            .parse::<Almanac>()
+            .unwrap();
+        let b = "seeds: 1\n\nwater-to-light map:\n1 2 3"
+            .parse::<Almanac>()
+            .unwrap();
+
+        assert_eq!(
+            a.chain(b),
+            Err(AocError::from(
+                "chain almanac ending in soil with one starting at water"
+            ))
+        );
+    }
+
+    #[test]
+    fn it_merges_almanacs() {
+        let a = "seeds: 1\n\nseed-to-soil map:\n50 98 2\n\nsoil-to-water map:\n1 2 3"
+            .parse::<Almanac>()
+            .unwrap();
+        let b = "seeds: 2\n\nseed-to-soil map:\n52 50 48"
+            .parse::<Almanac>()
+            .unwrap();
+
+        let almanac = a.merge(b).unwrap();
+
+        assert_eq!(almanac.seeds, Seeds(vec![1]));
+        assert_eq!(almanac.mapping_tables[0].mappings.len(), 2);
+        assert_eq!(almanac.mapping_tables[1].mappings.len(), 1);
+    }
+
+    #[test]
+    fn it_refuses_to_merge_unknown_tables() {
+        let a = "seeds: 1\n\nseed-to-soil map:\n50 98 2"
+            .parse::<Almanac>()
+            .unwrap();
+        let b = "seeds: 2\n\nsoil-to-water map:\n52 50 48"
+            .parse::<Almanac>()
+            .unwrap();
+
+        assert_eq!(
+            a.merge(b),
+            Err(AocError::from("no soil-to-water table to merge into"))
+        );
+    }
+
+    // Small xorshift generator so the randomized tests stay reproducible
+    struct Rng(u64);
+
+    impl Rng {
+        fn below(&mut self, n: Number) -> Number {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0 % n
+        }
+
+        fn range(&mut self, max: Number) -> Range<Number> {
+            let start = self.below(max);
+            start..(start + self.below(max / 2))
+        }
+
+        fn almanac(&mut self) -> Almanac {
+            let mapping_tables = (0..1 + self.below(4))
+                .map(|t| MappingTable {
+                    from_label: format!("c{t}"),
+                    to_label: format!("c{}", t + 1),
+                    mappings: (0..self.below(5))
+                        .map(|_| Mapping::new(self.below(50), self.below(50), self.below(15)))
+                        .collect(),
+                })
+                .collect();
+
+            // Pairs of a start and length, the ranges of part 2
+            let seeds = (0..1 + self.below(3))
+                .flat_map(|_| [self.below(80), self.below(20)])
+                .collect();
+
+            Almanac {
+                seeds: Seeds(seeds),
+                mapping_tables,
+            }
+        }
+    }
+
+    #[test]
+    fn part_2_agrees_with_brute_force() {
+        let mut rng = Rng(0x9E3779B97F4A7C15);
+
+        for _ in 0..500 {
+            let almanac = rng.almanac();
+
+            // Every seed of every range mapped on its own
+            let lowest = almanac
+                .seed_ranges()
+                .into_iter()
+                .flatten()
+                .map(|seed| {
+                    almanac
+                        .mapping_tables
+                        .iter()
+                        .fold(seed, |s, table| table.map(s))
+                })
+                .min();
+
+            assert_eq!(part_2(&almanac), lowest, "{almanac:?}");
+        }
+    }
+
+    #[test]
+    fn seed_ranges_agree_with_brute_force() {
+        let mut rng = Rng(0x2545F4914F6CDD1D);
+
+        for _ in 0..500 {
+            let almanac = rng.almanac();
+            let locations = rng.range(80);
+            let seeds = rng.range(80);
+
+            let seed_ranges = almanac.seed_ranges_for(locations.clone());
+
+            for seed in seeds {
+                let location = almanac
+                    .mapping_tables
+                    .iter()
+                    .fold(seed, |s, table| table.map(s));
+
+                assert_eq!(
+                    seed_ranges.iter().any(|r| r.contains(&seed)),
+                    locations.contains(&location),
+                    "seed {seed} -> location {location}, locations {locations:?}\n{almanac:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn part_1_does_not_depend_on_seed_order() {
+        const INPUT: &str = include_str!("./example.txt");
+
+        let mut almanac = INPUT.parse::<Almanac>().unwrap();
+
+        for i in 0..almanac.seeds.0.len() {
+            almanac.seeds.0.rotate_left(i);
+            assert_eq!(almanac.get_mapped_seeds().into_iter().min(), Some(35));
+
+            almanac.seeds.0.reverse();
+            assert_eq!(almanac.get_mapped_seeds().into_iter().min(), Some(35));
+        }
+    }
+}
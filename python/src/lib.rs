@@ -0,0 +1,85 @@
+// The solutions as a Python extension module, to call from notebooks:
+//
+//     maturin develop -m python/Cargo.toml --release
+//
+//     >>> import aoc23
+//     >>> aoc23.solve(4, 2, open("input.txt").read())
+//     30
+
+// Tripped by the code #[pyfunction] generates for errors
+#![allow(clippy::useless_conversion)]
+
+use aoc_common::input::normalize_newlines;
+use aoc_common::{registry, Answer, AocError, DEFAULT_YEAR};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+// Days register themselves with aoc_common, they only need to be linked in
+use day_01 as _;
+use day_02 as _;
+use day_03 as _;
+use day_04 as _;
+use day_05 as _;
+
+fn answer(year: u16, day: u8, part: u8, input: &str) -> Result<Answer, AocError> {
+    registry::solve(year, day, part, &normalize_newlines(input))
+}
+
+// Numbers become Python ints, which are as big as they need to be
+fn to_python(py: Python<'_>, answer: Answer) -> PyObject {
+    match answer {
+        Answer::Unsigned(n) => n.into_py(py),
+        Answer::Signed(n) => n.into_py(py),
+        Answer::Text(s) => s.into_py(py),
+    }
+}
+
+/// Solves a part of a day from its input, raising ValueError when that fails.
+#[pyfunction]
+#[pyo3(signature = (day, part, input, year = DEFAULT_YEAR))]
+fn solve(py: Python<'_>, day: u8, part: u8, input: &str, year: u16) -> PyResult<PyObject> {
+    // Other Python threads carry on while a slow part is solved
+    let answer = py
+        .allow_threads(|| answer(year, day, part, input))
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+    Ok(to_python(py, answer))
+}
+
+/// The registered days of a year.
+#[pyfunction]
+#[pyo3(signature = (year = DEFAULT_YEAR))]
+fn days(year: u16) -> Vec<u8> {
+    registry::days()
+        .into_iter()
+        .filter(|(y, _)| *y == year)
+        .map(|(_, day)| day)
+        .collect()
+}
+
+#[pymodule]
+fn aoc23(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(solve, module)?)?;
+    module.add_function(wrap_pyfunction!(days, module)?)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_solves_pasted_inputs() {
+        let card = "Card 1: 41 48 83 | 83 86 6 48\r\n";
+
+        assert_eq!(answer(2023, 4, 1, card), Ok(Answer::Unsigned(2)));
+        assert!(answer(2023, 24, 1, card).is_err());
+    }
+
+    #[test]
+    fn it_links_every_day() {
+        assert_eq!(days(2023), vec![1, 2, 3, 4, 5]);
+    }
+}
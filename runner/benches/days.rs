@@ -0,0 +1,48 @@
+use aoc_common::input::load_input;
+use aoc_common::registry;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// Days register themselves with aoc_common, they only need to be linked in
+use day_01 as _;
+use day_02 as _;
+use day_03 as _;
+use day_04 as _;
+use day_05 as _;
+
+// Benchmarks parsing and both parts of every registered day against the real
+// input. Days whose input can't be loaded or parsed are skipped, and so are
+// parts that aren't implemented.
+fn days(c: &mut Criterion) {
+    for (year, day) in registry::days() {
+        let Some(solution) = registry::find(year, day) else {
+            continue;
+        };
+        let input = match load_input(year, day) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Skipping {year} day {day}: {e}");
+                continue;
+            }
+        };
+        let parsed = match solution.parse(&input) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                eprintln!("Skipping {year} day {day}: {e}");
+                continue;
+            }
+        };
+
+        let mut group = c.benchmark_group(format!("{year}/day_{day:02}"));
+        group.bench_function("parse", |b| b.iter(|| solution.parse(black_box(&input))));
+        for part in [1, 2] {
+            if solution.solve(part, &parsed).is_ok() {
+                group.bench_function(format!("part_{part}"), |b| {
+                    b.iter(|| solution.solve(part, black_box(&parsed)))
+                });
+            }
+        }
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
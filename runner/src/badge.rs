@@ -0,0 +1,146 @@
+use std::path::Path;
+
+use aoc_common::AocError;
+
+use crate::check::Check;
+use crate::history;
+
+const COLUMNS: u8 = 5;
+const WIDTH: u32 = 64;
+const HEIGHT: u32 = 44;
+const HEADER: u32 = 32;
+
+// A day of the calendar: how many stars it has and how long its last
+// recorded run took
+#[derive(Debug, Default, PartialEq)]
+struct Day {
+    day: u8,
+    stars: usize,
+    ms: Option<f64>,
+}
+
+// Parts with a confirmed answer are the stars. The time is parsing once and
+// solving each part, as last recorded.
+fn days(year: u16) -> Result<Vec<Day>, AocError> {
+    let check = Check::new()?;
+    let db = history::open()?;
+
+    let mut days = Vec::new();
+    for day in 1..=25 {
+        let stars = (1..=2)
+            .filter(|&part| check.expected(year, day, part).is_some())
+            .count();
+        let mut runs = Vec::new();
+        for part in 1..=2 {
+            runs.extend(history::runs(&db, year, day, Some(part), 1)?);
+        }
+        let ms = runs
+            .first()
+            .map(|first| first.parse_ms + runs.iter().map(|run| run.solve_ms).sum::<f64>());
+
+        days.push(Day { day, stars, ms });
+    }
+
+    Ok(days)
+}
+
+fn short_time(ms: f64) -> String {
+    if ms < 1.0 {
+        format!("{:.0}µs", ms * 1000.0)
+    } else if ms < 1000.0 {
+        format!("{ms:.1}ms")
+    } else {
+        format!("{:.1}s", ms / 1000.0)
+    }
+}
+
+// The calendar as a standalone SVG: the stars so far above a grid of the
+// days, gold with both stars and silver with one
+fn svg(year: u16, days: &[Day]) -> String {
+    let stars: usize = days.iter().map(|day| day.stars).sum();
+    let rows = (days.len() as u32).div_ceil(COLUMNS.into());
+    let width = WIDTH * u32::from(COLUMNS);
+    let height = HEADER + HEIGHT * rows;
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         font-family=\"monospace\" font-size=\"11\" text-anchor=\"middle\">\n\
+         <rect width=\"{width}\" height=\"{height}\" fill=\"#0f0f23\"/>\n\
+         <text x=\"{}\" y=\"21\" font-size=\"15\" fill=\"#ffff66\">AoC {year} ★ {stars}/50</text>\n",
+        width / 2
+    );
+    for (i, day) in days.iter().enumerate() {
+        let x = WIDTH * (i as u32 % u32::from(COLUMNS));
+        let y = HEADER + HEIGHT * (i as u32 / u32::from(COLUMNS));
+        let (fill, text) = match day.stars {
+            0 => ("#333340", "#cccccc"),
+            1 => ("#9999cc", "#0f0f23"),
+            _ => ("#ffff66", "#0f0f23"),
+        };
+        let time = day.ms.map(short_time).unwrap_or_default();
+
+        svg += &format!(
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"4\" fill=\"{fill}\"/>\n\
+             <text x=\"{}\" y=\"{}\" fill=\"{text}\" font-weight=\"bold\">{}</text>\n\
+             <text x=\"{}\" y=\"{}\" fill=\"{text}\" font-size=\"9\">{time}</text>\n",
+            x + 2,
+            y + 2,
+            WIDTH - 4,
+            HEIGHT - 4,
+            x + WIDTH / 2,
+            y + 18,
+            day.day,
+            x + WIDTH / 2,
+            y + 33,
+        );
+    }
+    svg += "</svg>\n";
+
+    svg
+}
+
+// Writes the calendar of a year from the recorded runs and known answers
+pub fn write(year: u16, path: &Path) -> Result<(), AocError> {
+    let svg = svg(year, &days(year)?);
+    std::fs::write(path, svg).map_err(|e| AocError::io("write", path, e))?;
+
+    println!("Saved {}", path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_shortens_times() {
+        assert_eq!(short_time(0.42), "420µs");
+        assert_eq!(short_time(12.345), "12.3ms");
+        assert_eq!(short_time(2500.0), "2.5s");
+    }
+
+    #[test]
+    fn it_draws_the_calendar() {
+        let mut days: Vec<Day> = (1..=25)
+            .map(|day| Day {
+                day,
+                ..Day::default()
+            })
+            .collect();
+        days[0].stars = 2;
+        days[0].ms = Some(0.5);
+        days[3].stars = 1;
+
+        let svg = svg(2023, &days);
+
+        assert!(svg
+            .starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"320\" height=\"252\""));
+        assert!(svg.contains(">AoC 2023 ★ 3/50</text>"));
+        assert_eq!(svg.matches("fill=\"#ffff66\"/>").count(), 1);
+        assert_eq!(svg.matches("fill=\"#9999cc\"/>").count(), 1);
+        assert!(svg.contains(">500µs</text>"));
+        assert!(svg.contains(">25</text>"));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+}
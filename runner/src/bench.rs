@@ -0,0 +1,295 @@
+use std::fmt::Display;
+use std::hint::black_box;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use aoc_common::input::load_input;
+use aoc_common::{cache, registry, AocError};
+use serde::{Deserialize, Serialize};
+
+use crate::color;
+
+// How long repeated runs of one phase took, in nanoseconds
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub mean_ns: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    fn of(times: &[Duration]) -> Stats {
+        let ns: Vec<u64> = times.iter().map(|t| t.as_nanos() as u64).collect();
+
+        Stats {
+            mean_ns: ns.iter().sum::<u64>() / ns.len().max(1) as u64,
+            min_ns: ns.iter().copied().min().unwrap_or_default(),
+            max_ns: ns.iter().copied().max().unwrap_or_default(),
+        }
+    }
+}
+
+// One phase of a day: "parse", "part 1" or "part 2"
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Benchmark {
+    pub year: u16,
+    pub day: u8,
+    pub phase: String,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+fn repeat<T>(iterations: usize, mut f: impl FnMut() -> T) -> Vec<Duration> {
+    (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect()
+}
+
+// Runs parsing and each part of a day `iterations` times against the real
+// input. Parts that aren't implemented are left out.
+pub fn day(year: u16, day: u8, iterations: usize) -> Result<Vec<Benchmark>, AocError> {
+    let solution = registry::find(year, day).ok_or(format!("no solution for {year} day {day}"))?;
+    let input = load_input(year, day).map_err(|e| format!("Day {day}: {e}"))?;
+    let parsed = solution
+        .parse(&input)
+        .map_err(|e| format!("Day {day}: {e}"))?;
+
+    let benchmark = |phase: &str, times: &[Duration]| Benchmark {
+        year,
+        day,
+        phase: phase.to_string(),
+        stats: Stats::of(times),
+    };
+
+    let mut benchmarks = vec![benchmark(
+        "parse",
+        &repeat(iterations, || solution.parse(black_box(&input))),
+    )];
+    for part in [1, 2] {
+        if solution.solve(part, &parsed).is_ok() {
+            benchmarks.push(benchmark(
+                &format!("part {part}"),
+                &repeat(iterations, || solution.solve(part, black_box(&parsed))),
+            ));
+        }
+    }
+
+    Ok(benchmarks)
+}
+
+// Baselines are kept as JSON in the `baselines` directory of the cache
+fn baseline_file(name: &str) -> Result<PathBuf, AocError> {
+    Ok(cache::cache_dir()?
+        .join("baselines")
+        .join(format!("{name}.json")))
+}
+
+// Returns where the baseline was saved
+pub fn save_baseline(name: &str, benchmarks: &[Benchmark]) -> Result<PathBuf, AocError> {
+    let path = baseline_file(name)?;
+    let json = serde_json::to_string_pretty(benchmarks)
+        .map_err(|e| format!("save baseline {name}: {e}"))?;
+    cache::write(&path, &json)?;
+
+    Ok(path)
+}
+
+pub fn load_baseline(name: &str) -> Result<Vec<Benchmark>, AocError> {
+    let path = baseline_file(name)?;
+    let json =
+        std::fs::read_to_string(&path).map_err(|e| AocError::io("read baseline", &path, e))?;
+
+    serde_json::from_str(&json).map_err(|e| AocError::Parse(format!("read baseline {name}: {e}")))
+}
+
+// A benchmark next to the same phase in a baseline, if the baseline has it
+pub struct Comparison<'a> {
+    pub benchmark: &'a Benchmark,
+    pub baseline: Option<&'a Benchmark>,
+}
+
+impl Comparison<'_> {
+    // How much slower the mean got, in percent, negative when it got faster
+    pub fn change(&self) -> Option<f64> {
+        let before = self.baseline?.stats.mean_ns as f64;
+        let after = self.benchmark.stats.mean_ns as f64;
+
+        (before > 0.0).then(|| (after - before) / before * 100.0)
+    }
+
+    pub fn regressed(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|change| change > threshold)
+    }
+}
+
+pub fn compare<'a>(benchmarks: &'a [Benchmark], baseline: &'a [Benchmark]) -> Vec<Comparison<'a>> {
+    benchmarks
+        .iter()
+        .map(|benchmark| Comparison {
+            benchmark,
+            baseline: baseline.iter().find(|b| {
+                (b.year, b.day, &b.phase) == (benchmark.year, benchmark.day, &benchmark.phase)
+            }),
+        })
+        .collect()
+}
+
+// A benchmark as exported with `--output json`, with the change against the
+// baseline when compared to one
+#[derive(Serialize)]
+struct Record<'a> {
+    #[serde(flatten)]
+    benchmark: &'a Benchmark,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    change_pct: Option<f64>,
+}
+
+// One JSON record per line, so other tools can read them without parsing the
+// table
+pub fn to_json_lines(comparisons: &[Comparison]) -> Result<String, AocError> {
+    let mut lines = String::new();
+    for comparison in comparisons {
+        let record = Record {
+            benchmark: comparison.benchmark,
+            change_pct: comparison.change(),
+        };
+        lines += &serde_json::to_string(&record).map_err(|e| format!("export benchmark: {e}"))?;
+        lines.push('\n');
+    }
+
+    Ok(lines)
+}
+
+fn time(ns: u64) -> String {
+    format!("{:.3?}", Duration::from_nanos(ns))
+}
+
+// The benchmarks as a table, with the change against the baseline and
+// regressions beyond the threshold flagged when compared to one
+pub struct Table<'a> {
+    pub comparisons: &'a [Comparison<'a>],
+    pub threshold: f64,
+}
+
+impl Display for Table<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:>4}  {:<6}  {:>12}  {:>12}  {:>12}  {:>9}",
+            "day", "phase", "mean", "min", "max", "change"
+        )?;
+        for comparison in self.comparisons {
+            let b = comparison.benchmark;
+            let change = match comparison.change() {
+                Some(change) => format!("{change:+.1}%"),
+                None => String::new(),
+            };
+            write!(
+                f,
+                "{:>4}  {:<6}  {:>12}  {:>12}  {:>12}  {:>9}",
+                b.day,
+                b.phase,
+                time(b.stats.mean_ns),
+                time(b.stats.min_ns),
+                time(b.stats.max_ns),
+                change
+            )?;
+            if comparison.regressed(self.threshold) {
+                write!(f, "  {}", color::bad("regressed"))?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    fn benchmark(day: u8, phase: &str, mean_ns: u64) -> Benchmark {
+        Benchmark {
+            year: 2023,
+            day,
+            phase: phase.to_string(),
+            stats: Stats {
+                mean_ns,
+                min_ns: mean_ns / 2,
+                max_ns: mean_ns * 2,
+            },
+        }
+    }
+
+    #[test]
+    fn it_summarizes_times() {
+        let times = [100, 300, 200].map(Duration::from_nanos);
+
+        assert_eq!(
+            Stats::of(&times),
+            Stats {
+                mean_ns: 200,
+                min_ns: 100,
+                max_ns: 300
+            }
+        );
+    }
+
+    #[test]
+    fn it_flags_regressions() {
+        let baseline = [benchmark(4, "parse", 1000), benchmark(4, "part 1", 1000)];
+        let benchmarks = [
+            benchmark(4, "parse", 1050),
+            benchmark(4, "part 1", 1200),
+            benchmark(4, "part 2", 1000),
+        ];
+
+        let comparisons = compare(&benchmarks, &baseline);
+
+        assert_eq!(comparisons[0].change(), Some(5.0));
+        assert!(!comparisons[0].regressed(10.0));
+        assert!(comparisons[1].regressed(10.0));
+        assert_eq!(comparisons[2].change(), None);
+        assert!(!comparisons[2].regressed(10.0));
+
+        let table = Table {
+            comparisons: &comparisons,
+            threshold: 10.0,
+        }
+        .to_string();
+        assert_eq!(table.matches("regressed").count(), 1);
+        assert!(table.contains("+20.0%  regressed\n"));
+    }
+
+    #[test]
+    fn it_exports_json_lines() {
+        let baseline = [benchmark(4, "parse", 1000)];
+        let benchmarks = [benchmark(4, "parse", 1500), benchmark(4, "part 1", 10)];
+
+        assert_eq!(
+            to_json_lines(&compare(&benchmarks, &baseline)).unwrap(),
+            "{\"year\":2023,\"day\":4,\"phase\":\"parse\",\"mean_ns\":1500,\"min_ns\":750,\"max_ns\":3000,\"change_pct\":50.0}\n\
+             {\"year\":2023,\"day\":4,\"phase\":\"part 1\",\"mean_ns\":10,\"min_ns\":5,\"max_ns\":20}\n"
+        );
+    }
+
+    #[test]
+    fn it_reads_back_baselines() {
+        let benchmarks = vec![benchmark(4, "parse", 1000)];
+        let json = serde_json::to_string(&benchmarks).unwrap();
+
+        assert_eq!(
+            json,
+            r#"[{"year":2023,"day":4,"phase":"parse","mean_ns":1000,"min_ns":500,"max_ns":2000}]"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Vec<Benchmark>>(&json).unwrap(),
+            benchmarks
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use std::fmt::Display;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use aoc_common::answers::{answers_path, Answers};
+use aoc_common::attempts::{attempts_file, Attempts};
+use aoc_common::{cache, is_not_implemented, registry, AocError};
+
+use crate::color;
+
+// Exit codes of `--check` and `aoc verify`, 2 being what clap exits with on bad arguments
+pub const FAILED: i32 = 1;
+pub const WRONG: i32 = 3;
+
+// How the parts of a check turned out
+#[derive(Debug, Default, PartialEq)]
+pub struct Summary {
+    pub passed: usize,
+    pub wrong: usize,
+    pub failed: usize,
+    pub unknown: usize,
+}
+
+impl Summary {
+    // Failing to run at all outweighs a wrong answer
+    pub fn exit_code(&self) -> i32 {
+        if self.failed > 0 {
+            FAILED
+        } else if self.wrong > 0 {
+            WRONG
+        } else {
+            0
+        }
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} passed, {} wrong, {} failed, {} without a known answer",
+            self.passed, self.wrong, self.failed, self.unknown
+        )
+    }
+}
+
+// Runs `f`, turning a panic into an error. The panic message itself is
+// printed by the panic hook as usual.
+pub fn unwind<T>(f: impl FnOnce() -> Result<T, AocError>) -> Result<T, AocError> {
+    catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|panic| {
+        let message = panic
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+
+        Err(format!("panicked: {message}").into())
+    })
+}
+
+// Checks solutions against the confirmed answers in `answers.toml`, or else
+// the ones adventofcode.com accepted when they were submitted. When checking
+// an example, only its answers in `answers.toml` count.
+#[derive(Default)]
+pub struct Check {
+    answers: Answers,
+    pub example: Option<String>,
+    pub summary: Summary,
+}
+
+impl Check {
+    pub fn new() -> Result<Check, AocError> {
+        Ok(Check {
+            answers: Answers::load(&answers_path()?)?,
+            ..Check::default()
+        })
+    }
+
+    pub fn expected(&self, year: u16, day: u8, part: u8) -> Option<String> {
+        if let Some(example) = &self.example {
+            return self
+                .answers
+                .example(year, day, example, part)
+                .map(String::from);
+        }
+        if let Some(answer) = self.answers.get(year, day, part) {
+            return Some(answer.to_string());
+        }
+
+        let dir = cache::cache_dir().ok()?;
+        let attempts = Attempts::load(&attempts_file(&dir, year, day, part)).ok()?;
+        attempts.correct().map(String::from)
+    }
+
+    // Solves the parts of a day and compares them with their known answers.
+    // Parts without a solution are left out unless asked for.
+    pub fn day(
+        &mut self,
+        year: u16,
+        day: u8,
+        parts: &[u8],
+        explicit: bool,
+        input: Result<String, AocError>,
+    ) {
+        let prepared = || {
+            let solution =
+                registry::find(year, day).ok_or(format!("no solution for {year} day {day}"))?;
+            let input = input?;
+            let parsed = unwind(|| solution.parse(&input))?;
+
+            Ok::<_, AocError>((solution, parsed))
+        };
+        let (solution, parsed) = match prepared() {
+            Ok(prepared) => prepared,
+            Err(e) => {
+                println!("Day {day}: {}", color::bad(e));
+                self.summary.failed += parts.len();
+                return;
+            }
+        };
+
+        for &part in parts {
+            match (
+                unwind(|| solution.solve(part, &parsed)),
+                self.expected(year, day, part),
+            ) {
+                (Err(e), _) if is_not_implemented(&e) && !explicit => {}
+                (Err(e), _) => {
+                    println!("Day {day} Part {part}: {}", color::bad(e));
+                    self.summary.failed += 1;
+                }
+                (Ok(answer), Some(expected)) if answer.to_string() == expected => {
+                    println!("Day {day} Part {part}: {answer} {}", color::good("ok"));
+                    self.summary.passed += 1;
+                }
+                (Ok(answer), Some(expected)) => {
+                    println!(
+                        "Day {day} Part {part}: {}",
+                        color::bad(format!("{answer}, expected {expected}"))
+                    );
+                    self.summary.wrong += 1;
+                }
+                (Ok(answer), None) => {
+                    println!(
+                        "Day {day} Part {part}: {answer}, {}",
+                        color::warn("no known answer")
+                    );
+                    self.summary.unknown += 1;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_catches_panics() {
+        let result: Result<(), AocError> = unwind(|| panic!("index out of bounds"));
+
+        assert_eq!(result, Err(AocError::from("panicked: index out of bounds")));
+        assert_eq!(unwind(|| Ok(4)), Ok(4));
+    }
+
+    #[test]
+    fn it_picks_exit_codes() {
+        let mut summary = Summary {
+            passed: 3,
+            unknown: 1,
+            ..Summary::default()
+        };
+        assert_eq!(summary.exit_code(), 0);
+
+        summary.wrong = 1;
+        assert_eq!(summary.exit_code(), WRONG);
+
+        summary.failed = 1;
+        assert_eq!(summary.exit_code(), FAILED);
+        assert_eq!(
+            summary.to_string(),
+            "3 passed, 1 wrong, 1 failed, 1 without a known answer"
+        );
+    }
+
+    #[test]
+    fn it_fails_days_without_solutions() {
+        let mut check = Check::default();
+
+        check.day(2023, 24, &[1, 2], false, Ok(String::new()));
+
+        assert_eq!(check.summary.failed, 2);
+    }
+
+    #[test]
+    fn it_checks_examples_against_their_answers() {
+        let mut check = Check {
+            answers: Answers::parse("[2023.4]\npart1 = 21\n[2023.4.example]\npart1 = 13").unwrap(),
+            example: Some("example".to_string()),
+            ..Check::default()
+        };
+
+        assert_eq!(check.expected(2023, 4, 1), Some("13".to_string()));
+        assert_eq!(check.expected(2023, 4, 2), None);
+
+        check.example = Some("example_2".to_string());
+        assert_eq!(check.expected(2023, 4, 1), None);
+    }
+}
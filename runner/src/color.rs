@@ -0,0 +1,77 @@
+use std::fmt::Display;
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use owo_colors::{OwoColorize, Style};
+
+// Phases and days taking longer than this are shown in yellow
+pub const SLOW: Duration = Duration::from_secs(1);
+
+static DISABLED: AtomicBool = AtomicBool::new(false);
+
+// Output is colored when it goes to a terminal, unless turned off with
+// --no-color or by setting NO_COLOR (https://no-color.org)
+pub fn init(no_color: bool) {
+    let no_color = no_color || std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    DISABLED.store(no_color, Ordering::Relaxed);
+}
+
+pub fn stdout() -> bool {
+    !DISABLED.load(Ordering::Relaxed) && std::io::stdout().is_terminal()
+}
+
+pub fn stderr() -> bool {
+    !DISABLED.load(Ordering::Relaxed) && std::io::stderr().is_terminal()
+}
+
+fn paint(text: impl Display, style: Style, enabled: bool) -> String {
+    if enabled {
+        text.style(style).to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+// Answers and checks that passed
+pub fn good(text: impl Display) -> String {
+    paint(text, Style::new().green(), stdout())
+}
+
+// Wrong answers and failures printed on stdout
+pub fn bad(text: impl Display) -> String {
+    paint(text, Style::new().red(), stdout())
+}
+
+pub fn warn(text: impl Display) -> String {
+    paint(text, Style::new().yellow(), stdout())
+}
+
+// Errors printed on stderr
+pub fn error(text: impl Display) -> String {
+    paint(text, Style::new().red(), stderr())
+}
+
+// A time, in yellow when it's slow
+pub fn time(time: Duration, text: impl Display) -> String {
+    if time > SLOW {
+        warn(text)
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_only_paints_when_enabled() {
+        assert_eq!(paint(13, Style::new().green(), false), "13");
+        assert_eq!(
+            paint(13, Style::new().green(), true),
+            "\u{1b}[32m13\u{1b}[0m"
+        );
+    }
+}
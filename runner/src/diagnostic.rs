@@ -0,0 +1,91 @@
+use aoc_common::AocError;
+use miette::{Diagnostic, GraphicalReportHandler, GraphicalTheme, NamedSource, SourceSpan};
+use thiserror::Error;
+
+use crate::color;
+
+// A parse error with the input it was found in, for miette to draw
+#[derive(Debug, Diagnostic, Error)]
+#[error("{message}")]
+struct ParseDiagnostic {
+    message: String,
+    #[source_code]
+    input: NamedSource<String>,
+    #[label("here")]
+    span: SourceSpan,
+}
+
+// Draws a parse error under the lines of the input around it, pointing at
+// the part that's wrong. Errors that aren't at a place in the input get None.
+pub fn render(e: &AocError, name: &str, input: &str, colored: bool) -> Option<String> {
+    let AocError::ParseAt { message, span, .. } = e else {
+        return None;
+    };
+    let start = span.start.min(input.len());
+    let end = span.end.clamp(start, input.len());
+    let diagnostic = ParseDiagnostic {
+        message: message.clone(),
+        input: NamedSource::new(name, input.to_string()),
+        span: (start..end).into(),
+    };
+
+    let theme = if colored {
+        GraphicalTheme::unicode()
+    } else {
+        GraphicalTheme::unicode_nocolor()
+    };
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(theme)
+        .render_report(&mut out, &diagnostic)
+        .ok()?;
+
+    Some(out)
+}
+
+// Prints the drawing of a parse error on stderr, like compilers do, leaving
+// only a short error to report after it
+pub fn explain(e: AocError, name: &str, input: &str) -> AocError {
+    match render(&e, name, input, color::stderr()) {
+        Some(drawing) => {
+            eprint!("{drawing}");
+            format!("could not parse {name}").into()
+        }
+        None => e,
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_points_at_the_error() {
+        let input = "Card 1: 1 | 2\nCard 2: 1 x | 3\n";
+        let e = AocError::parse_at("parse number (x)", 24..25).locate(input);
+
+        let drawing = render(&e, "input.txt", input, false).unwrap();
+
+        assert!(drawing.contains("× parse number (x)"));
+        assert!(drawing.contains("[input.txt:2:11]"));
+        assert!(drawing.contains("2 │ Card 2: 1 x | 3"));
+        assert!(drawing.contains("here"));
+    }
+
+    #[test]
+    fn it_only_draws_parse_errors_with_a_place() {
+        assert_eq!(
+            render(&AocError::from("no seeds"), "input.txt", "", false),
+            None
+        );
+        assert_eq!(
+            render(
+                &AocError::Parse("split line".to_string()),
+                "input.txt",
+                "",
+                false
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use std::fmt::Display;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use aoc_common::input::workspace_dir;
+use aoc_common::{cache, AocError};
+use rusqlite::{params, Connection};
+
+use crate::output::PartResult;
+
+const SCHEMA: &str = "CREATE TABLE IF NOT EXISTS runs (
+    id INTEGER PRIMARY KEY,
+    timestamp INTEGER NOT NULL,
+    git_commit TEXT,
+    year INTEGER NOT NULL,
+    day INTEGER NOT NULL,
+    part INTEGER NOT NULL,
+    answer TEXT NOT NULL,
+    parse_ms REAL NOT NULL,
+    solve_ms REAL NOT NULL
+);
+CREATE INDEX IF NOT EXISTS runs_by_day ON runs (year, day, part);";
+
+// Every run is kept in `history.sqlite` in the cache directory
+pub fn open() -> Result<Connection, AocError> {
+    let dir = cache::cache_dir()?;
+    std::fs::create_dir_all(&dir).map_err(|e| AocError::io("create", &dir, e))?;
+
+    let path = dir.join("history.sqlite");
+    let db = Connection::open(&path)
+        .map_err(|e| AocError::io("open", &path, std::io::Error::other(e)))?;
+    init(&db)?;
+
+    Ok(db)
+}
+
+fn init(db: &Connection) -> Result<(), AocError> {
+    db.execute_batch(SCHEMA)
+        .map_err(|e| AocError::from(format!("create history: {e}")))
+}
+
+// The commit the workspace is at, marked `-dirty` with uncommitted changes,
+// so times can be matched to the code that produced them
+pub fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .current_dir(workspace_dir())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+// Adds the results of a run to the history
+pub fn save(results: &[PartResult]) -> Result<(), AocError> {
+    record(&open()?, results)
+}
+
+pub fn record(db: &Connection, results: &[PartResult]) -> Result<(), AocError> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let commit = git_commit();
+
+    for r in results {
+        db.execute(
+            "INSERT INTO runs (timestamp, git_commit, year, day, part, answer, parse_ms, solve_ms)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                timestamp,
+                commit,
+                r.year,
+                r.day,
+                r.part,
+                r.answer.to_string(),
+                r.parse_ms,
+                r.solve_ms
+            ],
+        )
+        .map_err(|e| format!("record run: {e}"))?;
+    }
+
+    Ok(())
+}
+
+// A recorded run of one part
+#[derive(Debug, PartialEq)]
+pub struct Run {
+    pub time: String,
+    pub git_commit: Option<String>,
+    pub part: u8,
+    pub answer: String,
+    pub parse_ms: f64,
+    pub solve_ms: f64,
+}
+
+// The latest runs of a day, newest first, limited to one part if given
+pub fn runs(
+    db: &Connection,
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+    limit: usize,
+) -> Result<Vec<Run>, AocError> {
+    let mut query = db
+        .prepare(
+            "SELECT datetime(timestamp, 'unixepoch', 'localtime'), git_commit, part, answer, parse_ms, solve_ms
+             FROM runs
+             WHERE year = ?1 AND day = ?2 AND (?3 IS NULL OR part = ?3)
+             ORDER BY timestamp DESC, id DESC
+             LIMIT ?4",
+        )
+        .map_err(|e| format!("query history: {e}"))?;
+
+    let runs = query
+        .query_map(params![year, day, part, limit], |row| {
+            Ok(Run {
+                time: row.get(0)?,
+                git_commit: row.get(1)?,
+                part: row.get(2)?,
+                answer: row.get(3)?,
+                parse_ms: row.get(4)?,
+                solve_ms: row.get(5)?,
+            })
+        })
+        .and_then(|rows| rows.collect())
+        .map_err(|e| format!("query history: {e}"))?;
+
+    Ok(runs)
+}
+
+// The runs as a table, e.g. for `aoc history --day 4`
+pub struct Table<'a>(pub &'a [Run]);
+
+impl Display for Table<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let commit_width = self
+            .0
+            .iter()
+            .filter_map(|run| run.git_commit.as_ref().map(|c| c.len()))
+            .chain(["commit".len()])
+            .max()
+            .unwrap_or_default();
+
+        writeln!(
+            f,
+            "{:<19}  {:<commit_width$}  {:>4}  {:>10}  {:>10}  answer",
+            "time", "commit", "part", "parse (ms)", "solve (ms)"
+        )?;
+        for run in self.0 {
+            writeln!(
+                f,
+                "{:<19}  {:<commit_width$}  {:>4}  {:>10.3}  {:>10.3}  {}",
+                run.time,
+                run.git_commit.as_deref().unwrap_or("-"),
+                run.part,
+                run.parse_ms,
+                run.solve_ms,
+                run.answer
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use aoc_common::Answer;
+
+    fn result(day: u8, part: u8, solve_ms: f64) -> PartResult {
+        PartResult {
+            year: 2023,
+            day,
+            part,
+            answer: Answer::from(13u64),
+            parse_ms: 0.5,
+            solve_ms,
+            verdict: None,
+        }
+    }
+
+    #[test]
+    fn it_records_runs() {
+        let db = Connection::open_in_memory().unwrap();
+        init(&db).unwrap();
+
+        record(&db, &[result(4, 1, 2.0), result(4, 2, 3.0)]).unwrap();
+        record(&db, &[result(4, 1, 1.0), result(5, 1, 9.0)]).unwrap();
+
+        let day_4 = runs(&db, 2023, 4, None, 10).unwrap();
+        assert_eq!(
+            day_4
+                .iter()
+                .map(|r| (r.part, r.solve_ms))
+                .collect::<Vec<_>>(),
+            vec![(1, 1.0), (2, 3.0), (1, 2.0)]
+        );
+        assert_eq!(runs(&db, 2023, 4, Some(1), 1).unwrap()[0].solve_ms, 1.0);
+        assert!(runs(&db, 2023, 3, None, 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_prints_runs() {
+        let run = Run {
+            time: "2023-12-04 06:00:00".to_string(),
+            git_commit: Some("abc1234-dirty".to_string()),
+            part: 2,
+            answer: "30".to_string(),
+            parse_ms: 0.5,
+            solve_ms: 1.25,
+        };
+
+        let table = Table(&[run]).to_string();
+
+        assert!(table.starts_with("time  "));
+        assert!(table
+            .contains("2023-12-04 06:00:00  abc1234-dirty     2       0.500       1.250  30\n"));
+    }
+}
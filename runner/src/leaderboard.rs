@@ -0,0 +1,253 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::time::Duration;
+
+use aoc_common::AocError;
+use serde::Deserialize;
+
+use crate::color;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Star {
+    get_star_ts: u64,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Member {
+    id: u64,
+    name: Option<String>,
+    stars: u32,
+    local_score: u32,
+    #[serde(default)]
+    last_star_ts: u64,
+    // The stars got on each day, by part
+    #[serde(default)]
+    completion_day_level: BTreeMap<u8, BTreeMap<u8, Star>>,
+}
+
+impl Member {
+    // Members who haven't set a name are shown like on the website
+    fn name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    fn stars_on(&self, day: u8) -> usize {
+        self.completion_day_level.get(&day).map_or(0, BTreeMap::len)
+    }
+
+    // How long after the puzzle unlocked the star of a part was got
+    fn time(&self, year: u16, day: u8, part: u8) -> Option<Duration> {
+        let star = self.completion_day_level.get(&day)?.get(&part)?;
+
+        Some(Duration::from_secs(
+            star.get_star_ts.saturating_sub(unlock(year, day)),
+        ))
+    }
+}
+
+// A private leaderboard as served by adventofcode.com
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    members: BTreeMap<String, Member>,
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Leaderboard, AocError> {
+        serde_json::from_str(json).map_err(|e| AocError::Parse(format!("parse leaderboard: {e}")))
+    }
+
+    // Highest score first, ties going to whoever got their last star first
+    fn ranked(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by_key(|m| (std::cmp::Reverse(m.local_score), m.last_star_ts, m.id));
+
+        members
+    }
+}
+
+// Days since 1970-01-01 of a date, from http://howardhinnant.github.io/date_algorithms.html
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+// Puzzles unlock at midnight EST, UTC-5
+fn unlock(year: u16, day: u8) -> u64 {
+    let days = days_from_civil(year.into(), 12, day.into());
+
+    (days * 86400 + 5 * 3600) as u64
+}
+
+fn hms(time: Duration) -> String {
+    let secs = time.as_secs();
+
+    format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+// Scores, stars and names, with a star for each day: `*` for both parts and
+// `+` for only the first
+pub struct Standings<'a>(pub &'a Leaderboard);
+
+impl Display for Standings<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ones: String = (1..=25).map(|day| char::from(b'0' + day % 10)).collect();
+        let tens: String = (1..=25)
+            .map(|day| {
+                if day < 10 {
+                    ' '
+                } else {
+                    char::from(b'0' + day / 10)
+                }
+            })
+            .collect();
+        writeln!(f, "{:>3}  {:>5}  {:>5}  {tens}", "", "", "")?;
+        writeln!(
+            f,
+            "{:>3}  {:>5}  {:>5}  {ones}  name",
+            "#", "score", "stars"
+        )?;
+
+        for (rank, member) in self.0.ranked().into_iter().enumerate() {
+            let days: String = (1..=25)
+                .map(|day| match member.stars_on(day) {
+                    0 => ".".to_string(),
+                    1 => "+".to_string(),
+                    _ => color::warn("*"),
+                })
+                .collect();
+            writeln!(
+                f,
+                "{:>3}  {:>5}  {:>5}  {days}  {}",
+                rank + 1,
+                member.local_score,
+                member.stars,
+                member.name()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+// How long everyone with a star on a day took to get each part, fastest to
+// finish first
+pub struct DayTimes<'a> {
+    pub leaderboard: &'a Leaderboard,
+    pub year: u16,
+    pub day: u8,
+}
+
+impl Display for DayTimes<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut times: Vec<(Option<Duration>, Option<Duration>, String)> = self
+            .leaderboard
+            .members
+            .values()
+            .filter(|m| m.stars_on(self.day) > 0)
+            .map(|m| {
+                (
+                    m.time(self.year, self.day, 2),
+                    m.time(self.year, self.day, 1),
+                    m.name(),
+                )
+            })
+            .collect();
+        // Finishing both parts comes before only having the first
+        times.sort_by_key(|(part2, part1, _)| (part2.is_none(), *part2, *part1));
+
+        writeln!(f, "{:>3}  {:>10}  {:>10}  name", "#", "part 1", "part 2")?;
+        for (rank, (part2, part1, name)) in times.into_iter().enumerate() {
+            writeln!(
+                f,
+                "{:>3}  {:>10}  {:>10}  {name}",
+                rank + 1,
+                part1.map(hms).unwrap_or_default(),
+                part2.map(hms).unwrap_or_default()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    // Day 1 of 2023 unlocked at 1701406800
+    const JSON: &str = r#"{
+        "event": "2023",
+        "owner_id": 1,
+        "members": {
+            "1": {"id": 1, "name": "Alice", "stars": 3, "local_score": 10, "global_score": 0,
+                  "last_star_ts": 1701500000,
+                  "completion_day_level": {
+                      "1": {"1": {"get_star_ts": 1701407100, "star_index": 1},
+                            "2": {"get_star_ts": 1701410400, "star_index": 2}},
+                      "2": {"1": {"get_star_ts": 1701500000, "star_index": 3}}
+                  }},
+            "2": {"id": 2, "name": null, "stars": 2, "local_score": 12, "global_score": 0,
+                  "last_star_ts": 1701408000,
+                  "completion_day_level": {
+                      "1": {"1": {"get_star_ts": 1701407000, "star_index": 1},
+                            "2": {"get_star_ts": 1701408000, "star_index": 2}}
+                  }},
+            "3": {"id": 3, "name": "Carol", "stars": 0, "local_score": 0, "global_score": 0,
+                  "last_star_ts": 0, "completion_day_level": {}}
+        }
+    }"#;
+
+    #[test]
+    fn it_finds_when_puzzles_unlock() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(unlock(2023, 1), 1701406800);
+        assert_eq!(unlock(2024, 25), 1735102800);
+    }
+
+    #[test]
+    fn it_ranks_members_by_score() {
+        let leaderboard = Leaderboard::parse(JSON).unwrap();
+        let standings = Standings(&leaderboard).to_string();
+        let lines: Vec<&str> = standings.lines().collect();
+
+        assert_eq!(lines.len(), 5);
+        assert!(lines[1].ends_with("1234567890123456789012345  name"));
+        assert_eq!(
+            lines[2],
+            "  1     12      2  *........................  (anonymous user #2)"
+        );
+        assert_eq!(
+            lines[3],
+            "  2     10      3  *+.......................  Alice"
+        );
+        assert!(lines[4].ends_with("  Carol"));
+    }
+
+    #[test]
+    fn it_times_a_day() {
+        let leaderboard = Leaderboard::parse(JSON).unwrap();
+        let times = DayTimes {
+            leaderboard: &leaderboard,
+            year: 2023,
+            day: 1,
+        }
+        .to_string();
+
+        assert_eq!(
+            times,
+            "  #      part 1      part 2  name\n\
+             \x20 1     0:03:20     0:20:00  (anonymous user #2)\n\
+             \x20 2     0:05:00     1:00:00  Alice\n"
+        );
+        assert!(Leaderboard::parse("{}").is_err());
+    }
+}
@@ -0,0 +1,49 @@
+use std::fs::File;
+use std::path::Path;
+
+use aoc_common::AocError;
+use tracing_chrome::{ChromeLayerBuilder, FlushGuard};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::EnvFilter;
+
+use crate::color;
+
+// Logs go to stderr, filtered by RUST_LOG if set, e.g. `RUST_LOG=day_04=debug`.
+// Only warnings are shown otherwise, unless --verbose asks for everything.
+//
+// With a trace file, the parse and solve spans are also written to it in the
+// Chrome trace format, whatever is logged. The trace is finished when the
+// returned guard is dropped.
+pub fn init(verbose: bool, trace: Option<&Path>) -> Result<Option<FlushGuard>, AocError> {
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new(if verbose { "debug" } else { "warn" }));
+    let span_events = if verbose {
+        FmtSpan::CLOSE
+    } else {
+        FmtSpan::NONE
+    };
+    let log = tracing_subscriber::fmt::layer()
+        .with_span_events(span_events)
+        .with_writer(std::io::stderr)
+        .with_ansi(color::stderr())
+        .with_filter(filter);
+
+    let (chrome, guard) = match trace {
+        Some(path) => {
+            let file = File::create(path).map_err(|e| AocError::io("create", path, e))?;
+            let (layer, guard) = ChromeLayerBuilder::new()
+                .writer(file)
+                .include_args(true)
+                .build();
+
+            (Some(layer.with_filter(LevelFilter::INFO)), Some(guard))
+        }
+        None => (None, None),
+    };
+
+    tracing_subscriber::registry().with(log).with(chrome).init();
+
+    Ok(guard)
+}
@@ -0,0 +1,839 @@
+use aoc_common::input::{example_path, load_input, normalize_newlines, read_input, select_input};
+use aoc_common::AocError;
+use aoc_common::{client, config, registry};
+use clap::{Args, CommandFactory, Parser, Subcommand};
+use output::{millis, Output, PartResult};
+use rayon::prelude::*;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use timing::Timings;
+
+mod badge;
+mod bench;
+mod check;
+mod color;
+mod diagnostic;
+mod history;
+mod leaderboard;
+mod logging;
+mod memory;
+mod notification;
+mod output;
+mod profile;
+mod puzzle;
+mod report;
+mod scaffold;
+mod serve;
+mod timing;
+mod tui;
+mod watch;
+
+// Counts allocations once enabled, for --time
+#[global_allocator]
+static ALLOCATOR: memory::Counting = memory::Counting;
+
+// Days register themselves with aoc_common, they only need to be linked in
+use day_01 as _;
+use day_02 as _;
+use day_03 as _;
+use day_04 as _;
+use day_05 as _;
+
+// Runs a single part of a day, e.g. `cargo run -p runner -- --day 4 --part 2`,
+// unless one of the other commands is given
+#[derive(Parser)]
+#[command(
+    name = "aoc",
+    args_override_self = true,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    run: RunArgs,
+
+    /// Threads for --parallel and for days that solve in parallel, all cores when left out
+    #[arg(long, global = true, env = "AOC_THREADS", value_parser = clap::value_parser!(u16).range(1..))]
+    threads: Option<u16>,
+
+    /// Log debug output of the days and the time spent in each span, RUST_LOG takes precedence
+    #[arg(short, long, global = true)]
+    verbose: bool,
+
+    /// Write the spans of the run as a Chrome trace, for chrome://tracing or Perfetto
+    #[arg(long, global = true, value_name = "FILE", num_args = 0..=1, default_missing_value = "trace.json")]
+    trace: Option<PathBuf>,
+
+    /// Don't color the output, even on a terminal. Setting NO_COLOR does the same
+    #[arg(long, global = true)]
+    no_color: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Download the puzzle description of a day as Markdown
+    FetchPuzzle(DayArgs),
+
+    /// Create the crate of a new day and add it to the workspace and runner
+    NewDay(DayArgs),
+
+    /// Benchmark days, optionally saving or comparing against a named baseline
+    Bench(BenchArgs),
+
+    /// Run a day under a sampling profiler and write a flamegraph SVG
+    Profile(ProfileArgs),
+
+    /// Solve days from their real input and compare with the answers in answers.toml
+    Verify(DaysArgs),
+
+    /// Show the recorded runs of a day, newest first
+    History(HistoryArgs),
+
+    /// Show the standings of a private leaderboard, or everyone's times on a day
+    Leaderboard(LeaderboardArgs),
+
+    /// Run a day again, or its tests, every time its source or inputs change
+    Watch(WatchArgs),
+
+    /// Serve the solutions over HTTP, answering `POST /day/{day}/part/{part}` with the input as body
+    Serve(ServeArgs),
+
+    /// Show every day of the year with its stars, last answers and timings, to run or open
+    Tui(TuiArgs),
+
+    /// Run days and write their answers and timings as CSV, Markdown or HTML
+    Report(ReportArgs),
+
+    /// Draw the stars and last recorded timings of the year as an SVG calendar
+    Badge(BadgeArgs),
+
+    /// Show what changed between two inputs of a day, e.g. an input and a hand-edited copy
+    DiffInput(DiffInputArgs),
+}
+
+#[derive(Args)]
+struct DayArgs {
+    /// Year of the puzzle, defaults to the year in aoc.toml
+    #[arg(short, long)]
+    year: Option<u16>,
+
+    // Optional only so a command can leave it out, clap requires it otherwise
+    /// Day of the puzzle
+    #[arg(short, long, required = true)]
+    day: Option<u8>,
+}
+
+impl DayArgs {
+    fn puzzle(&self) -> Result<(u16, u8), AocError> {
+        puzzle(self.year, self.day)
+    }
+}
+
+// The year and day of a puzzle, the year defaulting to the one in aoc.toml
+fn puzzle(year: Option<u16>, day: Option<u8>) -> Result<(u16, u8), AocError> {
+    let day = day.ok_or("--day is required")?;
+
+    Ok((year_or_default(year)?, day))
+}
+
+fn year_or_default(year: Option<u16>) -> Result<u16, AocError> {
+    match year {
+        Some(year) => Ok(year),
+        None => Ok(config::config()?.year),
+    }
+}
+
+// Every registered day of a year
+fn year_days(year: u16) -> Vec<u8> {
+    registry::days()
+        .into_iter()
+        .filter(|(y, _)| *y == year)
+        .map(|(_, day)| day)
+        .collect()
+}
+
+#[derive(Args)]
+struct HistoryArgs {
+    #[command(flatten)]
+    puzzle: DayArgs,
+
+    /// Only show runs of this part
+    #[arg(short, long)]
+    part: Option<u8>,
+
+    /// How many runs to show
+    #[arg(short = 'n', long, default_value_t = 20)]
+    limit: usize,
+}
+
+impl HistoryArgs {
+    fn show(&self) -> Result<(), AocError> {
+        let (year, day) = self.puzzle.puzzle()?;
+        let runs = history::runs(&history::open()?, year, day, self.part, self.limit)?;
+
+        if runs.is_empty() {
+            println!("No runs recorded for {year} day {day}");
+        } else {
+            print!("{}", history::Table(&runs));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Args)]
+struct LeaderboardArgs {
+    /// ID of the leaderboard, the number in its URL, defaults to the leaderboard in aoc.toml
+    id: Option<u64>,
+
+    /// Year of the leaderboard, defaults to the year in aoc.toml
+    #[arg(short, long)]
+    year: Option<u16>,
+
+    /// Show how long everyone took on this day instead
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+}
+
+impl LeaderboardArgs {
+    fn show(&self) -> Result<(), AocError> {
+        let year = year_or_default(self.year)?;
+        let id = match self.id {
+            Some(id) => id,
+            None => config::config()?
+                .leaderboard
+                .ok_or("no leaderboard given, pass its ID or set leaderboard in aoc.toml")?,
+        };
+        let leaderboard = leaderboard::Leaderboard::parse(&client::fetch_leaderboard(year, id)?)?;
+
+        match self.day {
+            Some(day) => print!(
+                "{}",
+                leaderboard::DayTimes {
+                    leaderboard: &leaderboard,
+                    year,
+                    day,
+                }
+            ),
+            None => print!("{}", leaderboard::Standings(&leaderboard)),
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Args)]
+struct DaysArgs {
+    /// Year of the puzzles, defaults to the year in aoc.toml
+    #[arg(short, long)]
+    year: Option<u16>,
+
+    /// Days to run, e.g. `--day 1,4`, every registered day of the year when left out
+    #[arg(short, long, value_delimiter = ',')]
+    day: Vec<u8>,
+}
+
+impl DaysArgs {
+    // Like --check, with the exit code to leave with
+    fn verify(&self) -> Result<i32, AocError> {
+        let (year, days) = self.days()?;
+        let mut check = check::Check::new()?;
+
+        for day in days {
+            check.day(year, day, &[1, 2], false, load_input(year, day));
+        }
+
+        println!("{}", check.summary);
+        Ok(check.summary.exit_code())
+    }
+
+    // The year and the days of it to run
+    fn days(&self) -> Result<(u16, Vec<u8>), AocError> {
+        let year = year_or_default(self.year)?;
+        let days = if self.day.is_empty() {
+            year_days(year)
+        } else {
+            self.day.clone()
+        };
+
+        Ok((year, days))
+    }
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    days: DaysArgs,
+
+    /// How many times to run each phase
+    #[arg(short = 'n', long, default_value_t = 20)]
+    iterations: usize,
+
+    /// Save the results as a baseline with this name
+    #[arg(long, value_name = "NAME")]
+    save_baseline: Option<String>,
+
+    /// Compare against the baseline with this name
+    #[arg(long, value_name = "NAME")]
+    baseline: Option<String>,
+
+    /// Flag phases whose mean time grew by more than this many percent
+    #[arg(long, default_value_t = 10.0, requires = "baseline")]
+    threshold: f64,
+
+    /// How to print the results, json gives one record per phase with times in ns
+    #[arg(short, long, value_enum, default_value_t)]
+    output: Output,
+}
+
+impl BenchArgs {
+    fn run(&self) -> Result<(), AocError> {
+        let (year, days) = self.days.days()?;
+        let baseline = match &self.baseline {
+            Some(name) => bench::load_baseline(name)?,
+            None => Vec::new(),
+        };
+
+        let mut benchmarks = Vec::new();
+        for day in days {
+            benchmarks.extend(bench::day(year, day, self.iterations)?);
+        }
+
+        let comparisons = bench::compare(&benchmarks, &baseline);
+        match self.output {
+            Output::Text => print!(
+                "{}",
+                bench::Table {
+                    comparisons: &comparisons,
+                    threshold: self.threshold,
+                }
+            ),
+            Output::Json => print!("{}", bench::to_json_lines(&comparisons)?),
+        }
+
+        if let Some(name) = &self.save_baseline {
+            let path = bench::save_baseline(name, &benchmarks)?;
+            // Keeps the JSON records on stdout parseable
+            match self.output {
+                Output::Text => println!("Saved baseline {name} to {}", path.display()),
+                Output::Json => eprintln!("Saved baseline {name} to {}", path.display()),
+            }
+        }
+
+        let regressions = comparisons
+            .iter()
+            .filter(|c| c.regressed(self.threshold))
+            .count();
+        match &self.baseline {
+            Some(name) if regressions > 0 => Err(format!(
+                "{regressions} phases regressed by more than {}% against {name}",
+                self.threshold
+            )
+            .into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Args)]
+struct ProfileArgs {
+    #[command(flatten)]
+    puzzle: DayArgs,
+
+    /// Part of the day to profile, both parts when left out
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// How many seconds to keep solving the day for
+    #[arg(short, long, default_value_t = 5.0)]
+    seconds: f64,
+
+    /// Where to write the flamegraph
+    #[arg(short, long, default_value = "flamegraph.svg")]
+    output: PathBuf,
+}
+
+impl ProfileArgs {
+    fn run(&self) -> Result<(), AocError> {
+        let (year, day) = self.puzzle.puzzle()?;
+        let duration = Duration::try_from_secs_f64(self.seconds)
+            .map_err(|e| format!("--seconds {}: {e}", self.seconds))?;
+
+        profile::flamegraph(year, day, self.part, duration, &self.output)
+    }
+}
+
+#[derive(Args)]
+struct ServeArgs {
+    /// Year of the puzzles when left out of the path, defaults to the year in aoc.toml
+    #[arg(short, long)]
+    year: Option<u16>,
+
+    /// Address to listen on
+    #[arg(short, long, default_value = "127.0.0.1:8023")]
+    address: String,
+}
+
+#[derive(Args)]
+struct TuiArgs {
+    /// Year of the puzzles, defaults to the year in aoc.toml
+    #[arg(short, long)]
+    year: Option<u16>,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    #[command(flatten)]
+    puzzle: DayArgs,
+
+    /// Part of the day to run, both parts when left out
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Solve the day's src/example.txt instead, or src/example_N.txt when given N
+    #[arg(long, value_name = "N", num_args = 0..=1, default_missing_value = "")]
+    example: Option<String>,
+
+    /// Run the day's tests instead of solving it
+    #[arg(long, conflicts_with_all = ["part", "example"])]
+    test: bool,
+}
+
+impl WatchArgs {
+    fn watch(&self) -> Result<(), AocError> {
+        let (year, day) = self.puzzle.puzzle()?;
+
+        watch::Watch {
+            year,
+            day,
+            part: self.part,
+            example: self.example.clone(),
+            test: self.test,
+        }
+        .watch()
+    }
+}
+
+#[derive(Args)]
+struct BadgeArgs {
+    /// Year of the puzzles, defaults to the year in aoc.toml
+    #[arg(short, long)]
+    year: Option<u16>,
+
+    /// Where to write the SVG
+    #[arg(short, long, default_value = "badge.svg")]
+    output: PathBuf,
+}
+
+#[derive(Args)]
+struct DiffInputArgs {
+    /// Year of the puzzle, defaults to the year in aoc.toml
+    #[arg(short, long)]
+    year: Option<u16>,
+
+    /// Day of the puzzle
+    day: u8,
+
+    /// The input to compare against
+    a: PathBuf,
+
+    /// The changed input
+    b: PathBuf,
+}
+
+impl DiffInputArgs {
+    // Only day 3 of 2023 knows how to diff its inputs, as schematics
+    fn diff(&self) -> Result<(), AocError> {
+        let year = year_or_default(self.year)?;
+        if (year, self.day) != (2023, 3) {
+            return Err(format!("no input diff for {year} day {}", self.day).into());
+        }
+
+        let parse = |path: &Path| {
+            let input = read_input(path)?;
+            day_03::Schematic::from_str(&input)
+                .map_err(|e| diagnostic::explain(e, &path.display().to_string(), &input))
+        };
+        let a = parse(&self.a)?;
+        let b = parse(&self.b)?;
+
+        print!("{}", a.diff(&b));
+        Ok(())
+    }
+}
+
+#[derive(Args)]
+struct ReportArgs {
+    #[command(flatten)]
+    days: DaysArgs,
+
+    #[command(flatten)]
+    formats: report::Formats,
+}
+
+impl ReportArgs {
+    fn write(&self) -> Result<(), AocError> {
+        let (year, days) = self.days.days()?;
+
+        report::write(year, &days, &self.formats)
+    }
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Year of the puzzle, defaults to the year in aoc.toml
+    #[arg(short, long)]
+    year: Option<u16>,
+
+    /// Day of the puzzle
+    #[arg(short, long, required_unless_present = "all")]
+    day: Option<u8>,
+
+    /// Run every registered day of the year instead, one after the other
+    #[arg(long, conflicts_with_all = ["day", "part", "input", "refresh", "submit", "example"])]
+    all: bool,
+
+    /// With --all, run the days concurrently, the answers are still printed in order
+    #[arg(long, requires = "all")]
+    parallel: bool,
+
+    /// Part of the day to run, both parts when left out
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Read the input from this file instead, `-` for stdin
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Download the input again instead of using the cached or local copy
+    #[arg(long)]
+    refresh: bool,
+
+    /// Submit the answer to adventofcode.com
+    #[arg(long, requires = "part")]
+    submit: bool,
+
+    /// Solve the day's src/example.txt instead, or src/example_N.txt when given N
+    #[arg(long, value_name = "N", num_args = 0..=1, default_missing_value = "", conflicts_with_all = ["input", "refresh", "submit"])]
+    example: Option<String>,
+
+    /// Compare the answers with answers.toml or the ones adventofcode.com accepted, exiting with 1 if a day
+    /// fails to run and with 3 if an answer is wrong
+    #[arg(long, conflicts_with_all = ["submit", "parallel", "time", "output"])]
+    check: bool,
+
+    /// Report how long loading and parsing the input and each part took, and the memory they used
+    #[arg(long)]
+    time: bool,
+
+    /// Show a desktop notification when a part takes longer than this many seconds, 0 to never
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        env = "AOC_NOTIFY_AFTER",
+        default_value_t = 30.0
+    )]
+    notify_after: f64,
+
+    /// How to print the answers
+    #[arg(short, long, value_enum, default_value_t)]
+    output: Output,
+}
+
+impl RunArgs {
+    // The file stem of the example to solve, if any
+    fn example(&self) -> Option<String> {
+        match self.example.as_deref()? {
+            "" => Some("example".to_string()),
+            n => Some(format!("example_{n}")),
+        }
+    }
+
+    // What the input is called when pointing at an error in it
+    fn input_name(&self) -> String {
+        match (self.example(), self.input.as_deref()) {
+            (Some(example), _) => format!("{example}.txt"),
+            (None, Some(path)) if path == Path::new("-") => "stdin".to_string(),
+            (None, Some(path)) => path.display().to_string(),
+            (None, None) => "input".to_string(),
+        }
+    }
+
+    fn input(&self, year: u16, day: u8) -> Result<String, AocError> {
+        if let Some(example) = self.example() {
+            read_input(example_path(year, day, &example))
+        } else if self.refresh {
+            client::fetch_input(year, day).map(|input| normalize_newlines(&input))
+        } else {
+            select_input(year, day, self.input.as_deref())
+        }
+    }
+}
+
+fn run(args: &RunArgs) -> Result<(), AocError> {
+    if args.all {
+        return run_all(args);
+    }
+
+    let (year, day) = puzzle(args.year, args.day)?;
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let notify_after = Duration::try_from_secs_f64(args.notify_after)
+        .map_err(|e| format!("--notify-after {}: {e}", args.notify_after))?;
+    let mut timings = Timings::default();
+    let solution = registry::find(year, day).ok_or(format!("no solution for {year} day {day}"))?;
+
+    let input = timings.time("load input", || args.input(year, day))?;
+
+    // The input is parsed once, both parts are solved from the same parse
+    let parsed = timings
+        .time("parse", || solution.parse(&input))
+        .map_err(|e| diagnostic::explain(e, &args.input_name(), &input))?;
+
+    let mut results = Vec::new();
+    for part in parts {
+        let phase = format!("part {part}");
+        let solved = timings.time(&phase, || solution.solve(part, &parsed));
+        notification::long_run(
+            day,
+            part,
+            &solved,
+            timings.get(&phase).unwrap_or_default(),
+            notify_after,
+        );
+        let answer = solved.map_err(|e| format!("Day {day} Part {part}: {e}"))?;
+
+        let verdict = if args.submit {
+            Some(client::submit(year, day, part, &answer)?)
+        } else {
+            None
+        };
+
+        let result = PartResult {
+            year,
+            day,
+            part,
+            answer,
+            parse_ms: timings.get("parse").map(millis).unwrap_or_default(),
+            solve_ms: timings.get(&phase).map(millis).unwrap_or_default(),
+            verdict,
+        };
+        result.print(args.output);
+        results.push(result);
+    }
+
+    // Only runs against the day's own input are recorded. Answers to examples,
+    // to another file or to stdin say nothing about the solutions' progress.
+    if args.example.is_none() && args.input.is_none() {
+        if let Err(e) = history::save(&results) {
+            eprintln!(
+                "{}",
+                color::error(format!("Not recorded in the history: {e}"))
+            );
+        }
+    }
+
+    // Keeps the JSON records on stdout parseable
+    if args.time {
+        match args.output {
+            Output::Text => print!("{timings}"),
+            Output::Json => eprint!("{timings}"),
+        }
+    }
+
+    Ok(())
+}
+
+// Runs every day of the year, like a report, and adds up how long parsing and
+// solving took. Days that fail are reported and the rest still run.
+fn run_all(args: &RunArgs) -> Result<(), AocError> {
+    let year = year_or_default(args.year)?;
+    let days = year_days(year);
+    let mut timings = Timings::default();
+    let mut results = Vec::new();
+    let mut failed = Vec::new();
+
+    let mut print_day = |day: u8, solved: Result<Vec<PartResult>, AocError>| match solved {
+        Ok(day_results) => {
+            let parse_ms = day_results.first().map(|r| r.parse_ms);
+            let solve_ms = day_results.iter().map(|r| r.solve_ms);
+            let ms: f64 = parse_ms.into_iter().chain(solve_ms).sum();
+            timings.add(&format!("day {day}"), Duration::from_secs_f64(ms / 1000.0));
+
+            for result in &day_results {
+                result.print(args.output);
+            }
+            results.extend(day_results);
+        }
+        Err(e) => {
+            eprintln!("{}", color::error(e));
+            failed.push(day.to_string());
+        }
+    };
+
+    let start = Instant::now();
+    if args.parallel {
+        // Collected in order of the days, so they're printed as if run one by one
+        let solved: Vec<_> = days
+            .par_iter()
+            .map(|&day| report::results(year, day))
+            .collect();
+        for (day, solved) in days.into_iter().zip(solved) {
+            print_day(day, solved);
+        }
+    } else {
+        for day in days {
+            print_day(day, report::results(year, day));
+        }
+    }
+    let wall_clock = start.elapsed();
+
+    if let Err(e) = history::save(&results) {
+        eprintln!(
+            "{}",
+            color::error(format!("Not recorded in the history: {e}"))
+        );
+    }
+
+    let mut summary = if args.time {
+        timings.to_string()
+    } else {
+        format!("Total: {:.3?}\n", timings.total())
+    };
+    // Days overlap when run concurrently, so their total isn't how long it took
+    if args.parallel {
+        summary += &format!("Wall clock: {wall_clock:.3?}\n");
+    }
+    // Keeps the JSON records on stdout parseable
+    match args.output {
+        Output::Text => print!("{summary}"),
+        Output::Json => eprint!("{summary}"),
+    }
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("failed days of {year}: {}", failed.join(", ")).into())
+    }
+}
+
+// Checks the answers of a day or, with --all, of every day, returning the
+// exit code
+fn check(args: &RunArgs) -> Result<i32, AocError> {
+    if args.all {
+        let all = DaysArgs {
+            year: args.year,
+            day: Vec::new(),
+        };
+        return all.verify();
+    }
+
+    let mut check = check::Check::new()?;
+    check.example = args.example();
+    let (year, day) = puzzle(args.year, args.day)?;
+    let input = args.input(year, day);
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    check.day(year, day, &parts, args.part.is_some(), input);
+
+    println!("{}", check.summary);
+    Ok(check.summary.exit_code())
+}
+
+fn main() {
+    // Defaults from aoc.toml come first so the actual arguments override them
+    let defaults = match config::config() {
+        Ok(config) => config.default_args.clone(),
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+    };
+    let argv: Vec<String> = std::env::args().collect();
+    let is_command = argv
+        .get(1)
+        .is_some_and(|arg| Cli::command().find_subcommand(arg).is_some());
+
+    let cli = if is_command {
+        Cli::parse_from(argv)
+    } else {
+        Cli::parse_from(
+            argv[..1]
+                .iter()
+                .cloned()
+                .chain(defaults)
+                .chain(argv[1..].iter().cloned()),
+        )
+    };
+
+    color::init(cli.no_color);
+
+    // Only a single day run reports the memory its phases used. Counting it
+    // slows down every allocation, so other commands leave it off.
+    if cli.command.is_none() && cli.run.time && !cli.run.all {
+        memory::enable();
+    }
+
+    // Held until the end, the trace is only complete once it's dropped
+    let trace = match logging::init(cli.verbose, cli.trace.as_deref()) {
+        Ok(trace) => trace,
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+    };
+
+    if let Some(threads) = cli.threads {
+        if let Err(e) = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads.into())
+            .build_global()
+        {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+    }
+
+    let mut exit_code = 0;
+    let result = match &cli.command {
+        Some(Command::FetchPuzzle(args)) => args
+            .puzzle()
+            .and_then(|(year, day)| puzzle::fetch(year, day)),
+        Some(Command::NewDay(args)) => args
+            .puzzle()
+            .and_then(|(year, day)| scaffold::new_day(year, day)),
+        Some(Command::Badge(args)) => {
+            year_or_default(args.year).and_then(|year| badge::write(year, &args.output))
+        }
+        Some(Command::Bench(args)) => args.run(),
+        Some(Command::DiffInput(args)) => args.diff(),
+        Some(Command::History(args)) => args.show(),
+        Some(Command::Leaderboard(args)) => args.show(),
+        Some(Command::Profile(args)) => args.run(),
+        Some(Command::Report(args)) => args.write(),
+        Some(Command::Serve(args)) => {
+            year_or_default(args.year).and_then(|year| serve::serve(&args.address, year))
+        }
+        Some(Command::Tui(args)) => year_or_default(args.year).and_then(tui::dashboard),
+        Some(Command::Watch(args)) => args.watch(),
+        Some(Command::Verify(args)) => args.verify().map(|code| exit_code = code),
+        None if cli.run.check => check(&cli.run).map(|code| exit_code = code),
+        None => run(&cli.run),
+    };
+
+    drop(trace);
+    if let Err(e) = result {
+        eprintln!("{}", color::error(e));
+        std::process::exit(1);
+    }
+    if exit_code != 0 {
+        std::process::exit(exit_code);
+    }
+}
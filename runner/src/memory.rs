@@ -0,0 +1,149 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+// The system allocator, keeping count of the bytes allocated so the memory
+// used by each phase of a run can be reported. Counting is off until
+// `enable` is called, so runs that only time phases, like benchmarks, don't
+// pay for updating the counters on every allocation.
+pub struct Counting;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static IN_USE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+fn grow(bytes: usize) {
+    if !enabled() {
+        return;
+    }
+    let in_use = IN_USE.fetch_add(bytes, Ordering::Relaxed) + bytes;
+    PEAK.fetch_max(in_use, Ordering::Relaxed);
+    ALLOCATED.fetch_add(bytes, Ordering::Relaxed);
+}
+
+fn shrink(bytes: usize) {
+    if !enabled() {
+        return;
+    }
+    // Memory allocated before counting started is freed without having been
+    // counted, so this stops at 0 instead of wrapping around
+    let _ = IN_USE.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| {
+        Some(n.saturating_sub(bytes))
+    });
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            if new_size > layout.size() {
+                grow(new_size - layout.size());
+            } else {
+                shrink(layout.size() - new_size);
+            }
+        }
+        new
+    }
+}
+
+// Memory used while running something: every byte allocated, and the most in
+// use at once on top of what was in use before. Allocations made by other
+// threads at the same time are counted too.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Usage {
+    pub allocated: usize,
+    pub peak: usize,
+}
+
+// Runs `f` and, while counting is enabled, measures the memory it used
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    if !enabled() {
+        return (f(), None);
+    }
+
+    let in_use = IN_USE.load(Ordering::Relaxed);
+    PEAK.store(in_use, Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+
+    let result = f();
+
+    let usage = Usage {
+        allocated: ALLOCATED.load(Ordering::Relaxed) - allocated,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(in_use),
+    };
+    (result, Some(usage))
+}
+
+// A size in bytes the way people read them, e.g. "1.5 MiB"
+pub fn bytes(n: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if n < 1024 {
+        return format!("{n} B");
+    }
+    let mut size = n as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_measures_allocations() {
+        enable();
+        let (v, usage) = measure(|| {
+            let scratch = vec![1u8; 4096];
+            drop(scratch);
+            vec![0u8; 1000]
+        });
+
+        // Other tests allocate at the same time, so only what's counted for
+        // sure can be checked
+        assert_eq!(v.len(), 1000);
+        assert!(usage.unwrap().allocated >= 5096);
+    }
+
+    #[test]
+    fn it_formats_bytes() {
+        assert_eq!(bytes(512), "512 B");
+        assert_eq!(bytes(1536), "1.5 KiB");
+        assert_eq!(bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+use aoc_common::{Answer, AocError};
+use notify_rust::Notification;
+
+use crate::color;
+
+// The title and text of the notification for a part that took `time`
+fn message(
+    day: u8,
+    part: u8,
+    solved: &Result<Answer, AocError>,
+    time: Duration,
+) -> (String, String) {
+    match solved {
+        Ok(answer) => (
+            format!("Day {day} Part {part} solved"),
+            format!("{answer} after {time:.1?}"),
+        ),
+        Err(e) => (
+            format!("Day {day} Part {part} failed"),
+            format!("{e} after {time:.1?}"),
+        ),
+    }
+}
+
+// Shows a desktop notification when a part took longer than `threshold` to
+// solve, so a long brute force can run in the background. A zero threshold
+// never notifies. Failing to notify is only reported.
+pub fn long_run(
+    day: u8,
+    part: u8,
+    solved: &Result<Answer, AocError>,
+    time: Duration,
+    threshold: Duration,
+) {
+    if threshold.is_zero() || time < threshold {
+        return;
+    }
+
+    let (summary, body) = message(day, part, solved, time);
+    if let Err(e) = Notification::new()
+        .appname("aoc")
+        .summary(&summary)
+        .body(&body)
+        .show()
+    {
+        eprintln!("{}", color::error(format!("Failed to notify: {e}")));
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_describes_the_run() {
+        let time = Duration::from_millis(125_240);
+
+        assert_eq!(
+            message(5, 2, &Ok(Answer::from(46u64)), time),
+            (
+                "Day 5 Part 2 solved".to_string(),
+                "46 after 125.2s".to_string()
+            )
+        );
+        assert_eq!(
+            message(5, 2, &Err(AocError::from("no seeds in almanac")), time).1,
+            "no seeds in almanac after 125.2s"
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use aoc_common::{Answer, Verdict};
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+
+use crate::color;
+
+#[derive(Clone, Copy, Default, ValueEnum)]
+pub enum Output {
+    #[default]
+    Text,
+    // One JSON record per line
+    Json,
+}
+
+// The outcome of solving one part, with how long parsing and solving took
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub parse_ms: f64,
+    pub solve_ms: f64,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "verdict_text"
+    )]
+    pub verdict: Option<Verdict>,
+}
+
+// Verdicts are exported the way they're printed
+fn verdict_text<S: Serializer>(
+    verdict: &Option<Verdict>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match verdict {
+        Some(verdict) => serializer.collect_str(verdict),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn paint(verdict: &Verdict) -> String {
+    match verdict {
+        Verdict::Correct => color::good(verdict),
+        Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong => color::bad(verdict),
+        Verdict::Wait(_) | Verdict::Unknown(_) => color::warn(verdict),
+        Verdict::AlreadySolved => verdict.to_string(),
+    }
+}
+
+pub fn millis(time: Duration) -> f64 {
+    time.as_secs_f64() * 1000.0
+}
+
+impl PartResult {
+    pub fn print(&self, output: Output) {
+        match output {
+            Output::Text => {
+                println!(
+                    "Day {} Part {}: {}",
+                    self.day,
+                    self.part,
+                    color::good(&self.answer)
+                );
+                if let Some(verdict) = &self.verdict {
+                    println!("Submitted {}: {}", self.answer, paint(verdict));
+                }
+            }
+            Output::Json => match serde_json::to_string(self) {
+                Ok(json) => println!("{json}"),
+                Err(e) => eprintln!(
+                    "{}",
+                    color::error(format!("Day {} Part {}: {e}", self.day, self.part))
+                ),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_serializes_results() {
+        let result = PartResult {
+            year: 2023,
+            day: 4,
+            part: 2,
+            answer: Answer::from(30u64),
+            parse_ms: 0.5,
+            solve_ms: millis(Duration::from_micros(1250)),
+            verdict: None,
+        };
+
+        assert_eq!(
+            serde_json::to_string(&result).unwrap(),
+            r#"{"year":2023,"day":4,"part":2,"answer":30,"parse_ms":0.5,"solve_ms":1.25}"#
+        );
+    }
+
+    #[test]
+    fn it_serializes_verdicts_as_text() {
+        let result = PartResult {
+            year: 2023,
+            day: 4,
+            part: 1,
+            answer: Answer::from(13u64),
+            parse_ms: 0.5,
+            solve_ms: 1.0,
+            verdict: Some(Verdict::TooLow),
+        };
+
+        assert!(serde_json::to_string(&result)
+            .unwrap()
+            .ends_with(r#""verdict":"wrong, too low"}"#));
+    }
+}
@@ -0,0 +1,66 @@
+use std::fs::File;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use aoc_common::input::load_input;
+use aoc_common::{registry, AocError};
+
+// Samples per second taken by the profiler
+const FREQUENCY: i32 = 1000;
+
+// Solves a day over and over for `duration` under a sampling profiler and
+// writes a flamegraph of where the time went. Days take anything from
+// microseconds to minutes, so they're repeated until enough samples are taken.
+// Without a part both are profiled, leaving out any not implemented yet.
+pub fn flamegraph(
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+    duration: Duration,
+    path: &Path,
+) -> Result<(), AocError> {
+    let solution = registry::find(year, day).ok_or(format!("no solution for {year} day {day}"))?;
+    let input = load_input(year, day)?;
+
+    let parsed = solution.parse(&input)?;
+    let parts: Vec<u8> = match part {
+        Some(part) => {
+            solution
+                .solve(part, &parsed)
+                .map_err(|e| format!("Day {day} Part {part}: {e}"))?;
+            vec![part]
+        }
+        None => [1, 2]
+            .into_iter()
+            .filter(|&part| solution.solve(part, &parsed).is_ok())
+            .collect(),
+    };
+
+    let profiler = pprof::ProfilerGuardBuilder::default()
+        .frequency(FREQUENCY)
+        .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+        .build()
+        .map_err(|e| format!("start profiler: {e}"))?;
+
+    let start = Instant::now();
+    let mut runs = 0;
+    while start.elapsed() < duration {
+        let parsed = solution.parse(&input)?;
+        for &part in &parts {
+            solution.solve(part, &parsed)?;
+        }
+        runs += 1;
+    }
+
+    let report = profiler
+        .report()
+        .build()
+        .map_err(|e| format!("profile: {e}"))?;
+    let file = File::create(path).map_err(|e| AocError::io("create", path, e))?;
+    report
+        .flamegraph(file)
+        .map_err(|e| AocError::io("write", path, std::io::Error::other(e)))?;
+
+    println!("Saved {} from {runs} runs", path.display());
+    Ok(())
+}
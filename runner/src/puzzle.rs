@@ -0,0 +1,217 @@
+use std::path::Path;
+
+use aoc_common::input::{day_crate, day_dir};
+use aoc_common::{client, html, AocError};
+
+// Saves the description of a day as `puzzle.md` in the day's crate. Fetching
+// again after solving part 1 adds the part 2 text.
+//
+// Code blocks in the description are saved as `src/example_N.txt` to pick the
+// examples from, existing files are left alone. Parts whose example answer
+// can be found get a test in `tests/examples.rs`, which is regenerated on
+// every fetch.
+pub fn fetch(year: u16, day: u8) -> Result<(), AocError> {
+    let html = client::fetch_puzzle(year, day)?;
+    let markdown =
+        to_markdown(&html).ok_or(format!("no puzzle description found for {year} day {day}"))?;
+
+    let dir = day_dir(year, day);
+    save(&dir.join("puzzle.md"), &markdown)?;
+
+    for (i, example) in examples(&html).iter().enumerate() {
+        let path = dir.join("src").join(format!("example_{}.txt", i + 1));
+        if path.exists() {
+            println!("Skipped {}, it already exists", path.display());
+        } else {
+            save(&path, example)?;
+        }
+    }
+
+    let tests = example_tests(year, day, &html);
+    if !tests.is_empty() {
+        let dir = dir.join("tests");
+        std::fs::create_dir_all(&dir).map_err(|e| AocError::io("create", &dir, e))?;
+        save(&dir.join("examples.rs"), &tests)?;
+    }
+
+    Ok(())
+}
+
+fn save(path: &Path, contents: &str) -> Result<(), AocError> {
+    std::fs::write(path, contents).map_err(|e| AocError::io("write", path, e))?;
+
+    println!("Saved {}", path.display());
+    Ok(())
+}
+
+// Each part of the puzzle is its own `<article class="day-desc">` on the page
+fn articles(html: &str) -> Vec<&str> {
+    html.split("<article class=\"day-desc\">")
+        .skip(1)
+        .filter_map(|rest| rest.split_once("</article>"))
+        .map(|(article, _)| article)
+        .collect()
+}
+
+fn to_markdown(html: &str) -> Option<String> {
+    let parts: Vec<String> = articles(html)
+        .into_iter()
+        .map(|article| html2md::parse_html(article).trim().to_string())
+        .collect();
+
+    (!parts.is_empty()).then(|| parts.join("\n\n") + "\n")
+}
+
+// The contents of every `<pre><code>` block, the way examples are shown
+fn examples(html: &str) -> Vec<String> {
+    html.split("<pre><code>")
+        .skip(1)
+        .filter_map(|rest| rest.split_once("</code></pre>"))
+        .map(|(code, _)| html::unescape(&html::strip_tags(code)))
+        .collect()
+}
+
+// The example answer is the last emphasized code in a part's text, as in
+// "... the total is <code><em>13</em></code>."
+fn example_answer(article: &str) -> Option<String> {
+    let (_, rest) = article.rsplit_once("<code><em>")?;
+    let (answer, _) = rest.split_once("</em></code>")?;
+
+    Some(html::unescape(answer))
+}
+
+// Tests solving each part's example through the registry. Part 2 usually
+// reuses the example of part 1, so a part without one of its own takes the
+// one before it.
+fn example_tests(year: u16, day: u8, html: &str) -> String {
+    let mut input = None;
+    let mut tests = Vec::new();
+
+    for (part, article) in (1..).zip(articles(html)) {
+        input = examples(article).into_iter().next().or(input);
+
+        if let (Some(input), Some(answer)) = (&input, example_answer(article)) {
+            tests.push(format!(
+                "#[test]\n\
+                 fn it_solves_part_{part}_example() {{\n    \
+                     let input = {};\n\n    \
+                     assert_eq!(registry::solve({year}, {day}, {part}, input).map(|a| a.to_string()), Ok({answer:?}.to_string()));\n\
+                 }}\n",
+                raw_string(input),
+            ));
+        }
+    }
+
+    if tests.is_empty() {
+        return String::new();
+    }
+
+    format!(
+        "// Generated by `aoc fetch-puzzle` from the puzzle's examples, changes are overwritten\n\n\
+         use aoc_common::registry;\n\
+         use {} as _;\n\n{}",
+        day_crate(year, day),
+        tests.join("\n")
+    )
+}
+
+// A raw string literal that can hold `s`, whatever quotes and hashes it has
+fn raw_string(s: &str) -> String {
+    let hashes = "#".repeat(
+        (0..)
+            .find(|n| !s.contains(&format!("\"{}", "#".repeat(*n))))
+            .unwrap_or(0)
+            .max(1),
+    );
+
+    format!("r{hashes}\"{s}\"{hashes}")
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_converts_each_part() {
+        let html = r#"<main>
+            <article class="day-desc"><h2>--- Day 4: Scratchcards ---</h2><p>Part <em>one</em></p></article>
+            <p>Your puzzle answer was <code>13</code>.</p>
+            <article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Part two</p></article>
+        </main>"#;
+
+        let markdown = to_markdown(html).unwrap();
+
+        assert!(markdown.contains("Day 4: Scratchcards"));
+        assert!(markdown.contains("Part *one*"));
+        assert!(markdown.contains("Part Two"));
+        assert!(!markdown.contains("Your puzzle answer"));
+    }
+
+    #[test]
+    fn it_extracts_examples() {
+        let html = r#"<article class="day-desc">
+            <p>For example:</p>
+            <pre><code>Card 1: 41 48 | <em>83</em> 86
+Card 2: 13 &lt; 32 &amp;&gt; 20
+</code></pre>
+            <p>The <code>inline</code> code isn't an example.</p>
+            <pre><code>467..114..</code></pre>
+        </article>"#;
+
+        assert_eq!(
+            examples(html),
+            vec![
+                "Card 1: 41 48 | 83 86\nCard 2: 13 < 32 &> 20\n".to_string(),
+                "467..114..".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn it_generates_example_tests() {
+        let html = r#"<article class="day-desc">
+            <pre><code>Card 1: 41 | 83
+</code></pre>
+            <p>The <code>inline</code> code isn't the answer, in total they're worth <code><em>13</em></code> points.</p>
+        </article>
+        <article class="day-desc">
+            <p>Including the copies, you end up with <code><em>30</em></code> scratchcards.</p>
+        </article>"#;
+
+        let tests = example_tests(2023, 4, html);
+
+        assert!(tests.contains("use day_04 as _;"));
+        assert!(tests.contains("fn it_solves_part_1_example()"));
+        assert!(tests.contains(
+            "registry::solve(2023, 4, 1, input).map(|a| a.to_string()), Ok(\"13\".to_string())"
+        ));
+        assert!(tests.contains(
+            "registry::solve(2023, 4, 2, input).map(|a| a.to_string()), Ok(\"30\".to_string())"
+        ));
+        assert_eq!(tests.matches("r#\"Card 1: 41 | 83\n\"#").count(), 2);
+    }
+
+    #[test]
+    fn it_skips_parts_without_answers() {
+        assert_eq!(
+            example_tests(
+                2023,
+                4,
+                "<article class=\"day-desc\"><p>No answer</p></article>"
+            ),
+            ""
+        );
+    }
+
+    #[test]
+    fn it_picks_raw_string_delimiters() {
+        assert_eq!(raw_string("a"), "r#\"a\"#");
+        assert_eq!(raw_string("\"#"), "r##\"\"#\"##");
+    }
+
+    #[test]
+    fn it_needs_a_description() {
+        assert_eq!(to_markdown("<main>Not logged in</main>"), None);
+    }
+}
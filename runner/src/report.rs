@@ -0,0 +1,352 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use aoc_common::input::load_input;
+use aoc_common::{html, registry, AocError};
+use clap::Args;
+
+use crate::color;
+use crate::history;
+use crate::output::{millis, PartResult};
+use crate::timing::Timings;
+
+// Solves both parts of a day from its usual input. Parts that fail, like ones
+// not implemented yet, are reported and left out.
+pub fn results(year: u16, day: u8) -> Result<Vec<PartResult>, AocError> {
+    let mut results = Vec::new();
+    for solved in solve(year, day)? {
+        match solved {
+            Ok(result) => results.push(result),
+            Err(e) => eprintln!("{}", color::error(e)),
+        }
+    }
+
+    Ok(results)
+}
+
+// Solves both parts of a day from its usual input, with the outcome of each
+pub fn solve(year: u16, day: u8) -> Result<Vec<Result<PartResult, AocError>>, AocError> {
+    let solution = registry::find(year, day).ok_or(format!("no solution for {year} day {day}"))?;
+    let input = load_input(year, day).map_err(|e| format!("Day {day}: {e}"))?;
+
+    let mut timings = Timings::default();
+    let parsed = timings
+        .time("parse", || solution.parse(&input))
+        .map_err(|e| format!("Day {day}: {e}"))?;
+
+    let mut results = Vec::new();
+    for part in [1, 2] {
+        let phase = format!("part {part}");
+        let result = timings
+            .time(&phase, || solution.solve(part, &parsed))
+            .map(|answer| PartResult {
+                year,
+                day,
+                part,
+                answer,
+                parse_ms: timings.get("parse").map(millis).unwrap_or_default(),
+                solve_ms: timings.get(&phase).map(millis).unwrap_or_default(),
+                verdict: None,
+            })
+            .map_err(|e| AocError::from(format!("Day {day} Part {part}: {e}")));
+        results.push(result);
+    }
+
+    Ok(results)
+}
+
+// The files a report is written to, at least one is needed
+#[derive(Args)]
+#[group(id = "format", required = true, multiple = true)]
+pub struct Formats {
+    /// Write the results as CSV to this file
+    #[arg(long)]
+    csv: Option<PathBuf>,
+
+    /// Write the results as a Markdown table to this file
+    #[arg(long)]
+    markdown: Option<PathBuf>,
+
+    /// Write the results as a standalone HTML page with timing charts to this file
+    #[arg(long)]
+    html: Option<PathBuf>,
+}
+
+// A row of a report: a solved part, or a day that couldn't be solved at all
+// with the reason why
+#[derive(Debug, PartialEq)]
+pub enum Row {
+    Solved(PartResult),
+    Failed { year: u16, day: u8, error: String },
+}
+
+// Runs the days and writes their results in each of the formats. Days that
+// fail get a row saying why and the rest still run, like `aoc --all`.
+pub fn write(year: u16, days: &[u8], formats: &Formats) -> Result<(), AocError> {
+    let mut rows = Vec::new();
+    let mut failed = Vec::new();
+    for &day in days {
+        match results(year, day) {
+            Ok(results) => rows.extend(results.into_iter().map(Row::Solved)),
+            Err(e) => {
+                eprintln!("{}", color::error(&e));
+                failed.push(day.to_string());
+                rows.push(Row::Failed {
+                    year,
+                    day,
+                    error: e.to_string(),
+                });
+            }
+        }
+    }
+
+    let solved: Vec<PartResult> = rows
+        .iter()
+        .filter_map(|row| match row {
+            Row::Solved(result) => Some(result.clone()),
+            Row::Failed { .. } => None,
+        })
+        .collect();
+    if let Err(e) = history::save(&solved) {
+        eprintln!(
+            "{}",
+            color::error(format!("Not recorded in the history: {e}"))
+        );
+    }
+
+    if let Some(path) = &formats.csv {
+        save(path, &csv(&rows))?;
+    }
+    if let Some(path) = &formats.markdown {
+        save(path, &markdown(&rows))?;
+    }
+    if let Some(path) = &formats.html {
+        save(path, &to_html(&rows))?;
+    }
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("failed days of {year}: {}", failed.join(", ")).into())
+    }
+}
+
+fn save(path: &Path, contents: &str) -> Result<(), AocError> {
+    std::fs::write(path, contents).map_err(|e| AocError::io("write", path, e))?;
+
+    println!("Saved {}", path.display());
+    Ok(())
+}
+
+pub fn csv(rows: &[Row]) -> String {
+    let mut csv = String::from("year,day,part,answer,parse_ms,solve_ms,error\n");
+    for row in rows {
+        csv += &match row {
+            Row::Solved(r) => format!(
+                "{},{},{},{},{:.3},{:.3},\n",
+                r.year,
+                r.day,
+                r.part,
+                csv_field(&r.answer.to_string()),
+                r.parse_ms,
+                r.solve_ms
+            ),
+            Row::Failed { year, day, error } => {
+                format!("{year},{day},,,,,{}\n", csv_field(error))
+            }
+        };
+    }
+
+    csv
+}
+
+// Quotes a field when it holds anything CSV gives a meaning to
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+// A table to paste into Markdown notes, without any heading around it
+pub fn markdown(rows: &[Row]) -> String {
+    let mut table = String::from(
+        "| Day | Part | Answer | Parse (ms) | Solve (ms) |\n\
+         |----:|-----:|:-------|-----------:|-----------:|\n",
+    );
+    for row in rows {
+        table += &match row {
+            Row::Solved(r) => format!(
+                "| {} | {} | {} | {:.3} | {:.3} |\n",
+                r.day,
+                r.part,
+                r.answer.to_string().replace('|', "\\|"),
+                r.parse_ms,
+                r.solve_ms
+            ),
+            Row::Failed { day, error, .. } => format!(
+                "| {day} | | failed: {} | | |\n",
+                error.replace('|', "\\|").replace('\n', " ")
+            ),
+        };
+    }
+
+    table
+}
+
+const STYLE: &str = "body { font-family: sans-serif; margin: 2em auto; max-width: 60em; }
+table { border-collapse: collapse; }
+th, td { padding: 0.2em 0.8em; border-bottom: 1px solid #ccc; text-align: right; }
+td.answer { text-align: left; font-family: monospace; }
+.chart { display: grid; grid-template-columns: 4em 1fr 7em; gap: 0.2em 0.5em; align-items: center; }
+.bar { height: 1em; background: #4a7; }
+.bar.parse { background: #47a; }";
+
+// A page with a table of every result and, for each day, bars for how long
+// parsing and each part took. Bars are scaled to the slowest phase of all
+// days so days can be compared at a glance. Days that failed only get a row
+// in the table.
+pub fn to_html(report: &[Row]) -> String {
+    let results: Vec<&PartResult> = report
+        .iter()
+        .filter_map(|row| match row {
+            Row::Solved(result) => Some(result),
+            Row::Failed { .. } => None,
+        })
+        .collect();
+    let slowest = results
+        .iter()
+        .flat_map(|r| [r.parse_ms, r.solve_ms])
+        .fold(0.0, f64::max);
+
+    let mut days: BTreeMap<(u16, u8), Vec<&PartResult>> = BTreeMap::new();
+    for r in &results {
+        days.entry((r.year, r.day)).or_default().push(r);
+    }
+
+    let mut rows = String::new();
+    for row in report {
+        rows += &match row {
+            Row::Solved(r) => format!(
+                "<tr><td>{}</td><td>{}</td><td class=\"answer\">{}</td><td>{:.3}</td><td>{:.3}</td></tr>\n",
+                r.day,
+                r.part,
+                html::escape(&r.answer.to_string()),
+                r.parse_ms,
+                r.solve_ms
+            ),
+            Row::Failed { day, error, .. } => format!(
+                "<tr><td>{day}</td><td></td><td class=\"answer\" colspan=\"3\">failed: {}</td></tr>\n",
+                html::escape(error)
+            ),
+        };
+    }
+
+    let mut charts = String::new();
+    for ((year, day), parts) in &days {
+        let phases = parts
+            .first()
+            .map(|r| ("parse".to_string(), r.parse_ms, "bar parse"))
+            .into_iter()
+            .chain(
+                parts
+                    .iter()
+                    .map(|r| (format!("part {}", r.part), r.solve_ms, "bar")),
+            );
+
+        charts += &format!("<h3>{year} day {day}</h3>\n<div class=\"chart\">\n");
+        for (phase, ms, class) in phases {
+            let width = if slowest > 0.0 {
+                ms / slowest * 100.0
+            } else {
+                0.0
+            };
+            charts += &format!(
+                "<span>{phase}</span><div class=\"{class}\" style=\"width: {width:.1}%\"></div><span>{ms:.3} ms</span>\n"
+            );
+        }
+        charts += "</div>\n";
+    }
+
+    format!(
+        "<!DOCTYPE html>\n\
+         <html>\n<head>\n<meta charset=\"utf-8\">\n<title>Advent of Code results</title>\n\
+         <style>\n{STYLE}\n</style>\n</head>\n<body>\n\
+         <h1>Advent of Code results</h1>\n\
+         <table>\n<tr><th>Day</th><th>Part</th><th>Answer</th><th>Parse (ms)</th><th>Solve (ms)</th></tr>\n\
+         {rows}</table>\n\
+         <h2>Timings</h2>\n{charts}</body>\n</html>\n"
+    )
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use aoc_common::Answer;
+
+    fn result(part: u8, answer: &str) -> Row {
+        Row::Solved(PartResult {
+            year: 2023,
+            day: 4,
+            part,
+            answer: Answer::from(answer),
+            parse_ms: 0.5,
+            solve_ms: 1.25,
+            verdict: None,
+        })
+    }
+
+    fn failed(day: u8, error: &str) -> Row {
+        Row::Failed {
+            year: 2023,
+            day,
+            error: error.to_string(),
+        }
+    }
+
+    #[test]
+    fn it_writes_csv() {
+        assert_eq!(
+            csv(&[
+                result(1, "13"),
+                result(2, "a,\"b\""),
+                failed(5, "Day 5: read input (x, y): no such file")
+            ]),
+            "year,day,part,answer,parse_ms,solve_ms,error\n\
+             2023,4,1,13,0.500,1.250,\n\
+             2023,4,2,\"a,\"\"b\"\"\",0.500,1.250,\n\
+             2023,5,,,,,\"Day 5: read input (x, y): no such file\"\n"
+        );
+    }
+
+    #[test]
+    fn it_writes_markdown() {
+        let table = markdown(&[result(1, "13"), result(2, "a|b"), failed(5, "no | input")]);
+
+        assert!(table.starts_with("| Day | Part |"));
+        assert!(table.contains("| 4 | 1 | 13 | 0.500 | 1.250 |\n"));
+        assert!(table.contains("| 4 | 2 | a\\|b |"));
+        assert!(table.ends_with("| 5 | | failed: no \\| input | | |\n"));
+    }
+
+    #[test]
+    fn it_writes_html() {
+        let mut slow = result(2, "<30>");
+        if let Row::Solved(slow) = &mut slow {
+            slow.solve_ms = 2.5;
+        }
+
+        let page = to_html(&[result(1, "13"), slow, failed(5, "no <input>")]);
+
+        assert!(page.starts_with("<!DOCTYPE html>"));
+        assert!(page.contains("<td class=\"answer\">&lt;30&gt;</td>"));
+        assert!(page.contains("<h3>2023 day 4</h3>"));
+        assert!(page.contains("<span>parse</span><div class=\"bar parse\" style=\"width: 20.0%\">"));
+        assert!(page.contains("<span>part 1</span><div class=\"bar\" style=\"width: 50.0%\">"));
+        assert!(page.contains("<span>part 2</span><div class=\"bar\" style=\"width: 100.0%\">"));
+        assert!(page.contains("<td class=\"answer\" colspan=\"3\">failed: no &lt;input&gt;</td>"));
+        assert!(!page.contains("<h3>2023 day 5</h3>"));
+    }
+}
@@ -0,0 +1,283 @@
+use std::path::Path;
+
+use aoc_common::input::{day_crate, day_dir, day_member, workspace_dir};
+use aoc_common::AocError;
+
+const CARGO_TOML: &str = r#"[package]
+name = "{crate}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc_common = { path = "{common}" }
+"#;
+
+const LIB_RS: &str = r#"use aoc_common::{not_implemented, Answer, AocError, Solution};
+
+pub struct {Day};
+
+aoc_common::register!({year}, {day}, {Day});
+
+impl Solution for {Day} {
+    type Parsed = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, AocError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(&self, _lines: &Self::Parsed) -> Result<Answer, AocError> {
+        Err(not_implemented(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::*;
+
+    const EXAMPLE: &str = include_str!("./example.txt");
+
+    #[test]
+    #[ignore = "fill in example.txt and the expected answer"]
+    fn it_solves_part_1_example() {
+        let lines = {Day}.parse(EXAMPLE).unwrap();
+
+        assert_eq!({Day}.part1(&lines), Ok(Answer::from(0u64)));
+    }
+
+    #[test]
+    #[ignore = "fill in example.txt and the expected answer"]
+    fn it_solves_part_2_example() {
+        let lines = {Day}.parse(EXAMPLE).unwrap();
+
+        assert_eq!({Day}.part2(&lines), Ok(Answer::from(0u64)));
+    }
+}
+"#;
+
+const MAIN_RS: &str = r#"use aoc_common::input::{input_arg, select_input};
+use aoc_common::Solution;
+use {crate}::{Day};
+
+fn main() {
+    let input = select_input({year}, {day}, input_arg().as_deref()).expect("read input");
+    let parsed = {Day}.parse(&input).expect("parse input");
+
+    match {Day}.part1(&parsed) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(e) => eprintln!("Part 1: {e}"),
+    }
+    match {Day}.part2(&parsed) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(e) => eprintln!("Part 2: {e}"),
+    }
+}
+"#;
+
+fn fill(template: &str, year: u16, day: u8) -> String {
+    let member = day_member(year, day);
+    let common = "../".repeat(member.matches('/').count() + 1) + "aoc_common";
+
+    template
+        .replace("{crate}", &day_crate(year, day))
+        .replace("{common}", &common)
+        .replace("{Day}", &format!("Day{day:02}"))
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+}
+
+// Creates the crate of a new day from the templates above, adds it to the
+// workspace and links it into the runner and the WebAssembly and Python builds
+pub fn new_day(year: u16, day: u8) -> Result<(), AocError> {
+    let dir = day_dir(year, day);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()).into());
+    }
+
+    let src = dir.join("src");
+    std::fs::create_dir_all(&src).map_err(|e| AocError::io("create", &src, e))?;
+
+    write(&dir.join("Cargo.toml"), &fill(CARGO_TOML, year, day))?;
+    write(&src.join("lib.rs"), &fill(LIB_RS, year, day))?;
+    write(&src.join("main.rs"), &fill(MAIN_RS, year, day))?;
+    write(&src.join("example.txt"), "")?;
+
+    let root = workspace_dir();
+    let crate_name = day_crate(year, day);
+
+    edit(&root.join("Cargo.toml"), |s| {
+        add_member(s, &day_member(year, day))
+    })?;
+    let linkers: [(&str, &[&str]); 3] = [
+        ("runner", &["src/main.rs", "benches/days.rs"]),
+        ("wasm", &["src/lib.rs"]),
+        ("python", &["src/lib.rs"]),
+    ];
+    for (linker, sources) in linkers {
+        edit(&root.join(linker).join("Cargo.toml"), |s| {
+            add_dependency(
+                s,
+                &format!(
+                    "{crate_name} = {{ path = \"../{}\" }}",
+                    day_member(year, day)
+                ),
+            )
+        })?;
+        for source in sources {
+            edit(&root.join(linker).join(source), |s| {
+                link_day(s, &crate_name)
+            })?;
+        }
+    }
+
+    println!("Created {}", dir.display());
+    Ok(())
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), AocError> {
+    std::fs::write(path, contents).map_err(|e| AocError::io("write", path, e))
+}
+
+fn edit(path: &Path, f: impl FnOnce(&str) -> Option<String>) -> Result<(), AocError> {
+    let contents = std::fs::read_to_string(path).map_err(|e| AocError::io("read", path, e))?;
+    let edited = f(&contents).ok_or(format!("don't know how to edit {}", path.display()))?;
+
+    write(path, &edited)
+}
+
+// Adds a member to the one line `members = [...]` of the workspace manifest
+fn add_member(manifest: &str, member: &str) -> Option<String> {
+    let (before, rest) = manifest.split_once("members = [")?;
+    let (list, after) = rest.split_once(']')?;
+
+    let mut members: Vec<&str> = list
+        .split(',')
+        .map(str::trim)
+        .filter(|m| !m.is_empty())
+        .collect();
+    let quoted = format!("\"{member}\"");
+    members.push(&quoted);
+
+    Some(format!("{before}members = [{}]{after}", members.join(", ")))
+}
+
+// Adds a line at the end of the `[dependencies]` section of a manifest
+fn add_dependency(manifest: &str, dependency: &str) -> Option<String> {
+    let start = manifest.find("[dependencies]\n")?;
+    let end = manifest[start..]
+        .find("\n[")
+        .map_or(manifest.len(), |i| start + i + 1);
+    let (before, after) = manifest.split_at(end);
+    let before = before.trim_end();
+
+    Some(format!(
+        "{before}\n{dependency}\n{}{after}",
+        if after.is_empty() { "" } else { "\n" }
+    ))
+}
+
+// Adds `use day_NN as _;` after the days the runner already links
+fn link_day(main: &str, crate_name: &str) -> Option<String> {
+    let last = main.rfind(" as _;\n")? + " as _;\n".len();
+
+    Some(format!(
+        "{}use {crate_name} as _;\n{}",
+        &main[..last],
+        &main[last..]
+    ))
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_fills_templates() {
+        let main = fill(MAIN_RS, 2023, 6);
+
+        assert!(main.contains("use day_06::Day06;"));
+        assert!(main.contains("select_input(2023, 6, "));
+        assert!(fill(CARGO_TOML, 2024, 6).contains("name = \"y2024_day_06\""));
+        assert!(fill(CARGO_TOML, 2024, 6).contains("path = \"../../aoc_common\""));
+    }
+
+    // Builds the filled templates as a crate of their own, outside the
+    // workspace so checking it doesn't add a member
+    #[test]
+    fn it_fills_templates_that_compile() {
+        let dir = std::env::temp_dir().join("aoc-scaffold-check");
+        let src = dir.join("src");
+        std::fs::create_dir_all(&src).unwrap();
+
+        let root = workspace_dir();
+        let manifest = fill(CARGO_TOML, 2023, 6).replace(
+            "\"../aoc_common\"",
+            &format!("{:?}", root.join("aoc_common")),
+        ) + "\n[workspace]\n";
+        std::fs::write(dir.join("Cargo.toml"), manifest).unwrap();
+        std::fs::write(src.join("lib.rs"), fill(LIB_RS, 2023, 6)).unwrap();
+        std::fs::write(src.join("main.rs"), fill(MAIN_RS, 2023, 6)).unwrap();
+        std::fs::write(src.join("example.txt"), "").unwrap();
+        // Same dependency versions as the workspace, so nothing is resolved again
+        if let Ok(lock) = std::fs::read(root.join("Cargo.lock")) {
+            std::fs::write(dir.join("Cargo.lock"), lock).unwrap();
+        }
+
+        let output = std::process::Command::new(std::env::var("CARGO").unwrap_or("cargo".into()))
+            .args(["check", "--quiet", "--all-targets", "--target-dir"])
+            .arg(root.join("target").join("scaffold"))
+            .current_dir(&dir)
+            .output()
+            .unwrap();
+
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    fn it_adds_workspace_members() {
+        let manifest = "[workspace]\nmembers = [\"aoc_common\", \"day_01\"]\n";
+
+        assert_eq!(
+            add_member(manifest, "day_02"),
+            Some("[workspace]\nmembers = [\"aoc_common\", \"day_01\", \"day_02\"]\n".to_string())
+        );
+    }
+
+    #[test]
+    fn it_adds_dependencies() {
+        let manifest =
+            "[dependencies]\nday_01 = \"1\"\n\n[dev-dependencies]\ncriterion = \"0.5\"\n";
+
+        assert_eq!(
+            add_dependency(manifest, "day_02 = \"1\""),
+            Some(
+                "[dependencies]\nday_01 = \"1\"\nday_02 = \"1\"\n\n[dev-dependencies]\ncriterion = \"0.5\"\n"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            add_dependency("[dependencies]\nday_01 = \"1\"\n", "day_02 = \"1\""),
+            Some("[dependencies]\nday_01 = \"1\"\nday_02 = \"1\"\n".to_string())
+        );
+    }
+
+    #[test]
+    fn it_links_days_into_the_runner() {
+        let main = "use clap::Parser;\n\nuse day_01 as _;\nuse day_02 as _;\n\nfn main() {}\n";
+
+        assert_eq!(
+            link_day(main, "day_03"),
+            Some(
+                "use clap::Parser;\n\nuse day_01 as _;\nuse day_02 as _;\nuse day_03 as _;\n\nfn main() {}\n"
+                    .to_string()
+            )
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use std::io::Read;
+use std::time::Instant;
+
+use aoc_common::input::normalize_newlines;
+use aoc_common::{registry, AocError};
+use serde_json::json;
+use tiny_http::{Header, Method, Response, Server};
+
+use crate::check::unwind;
+use crate::memory;
+use crate::output::{millis, PartResult};
+
+// Inputs are a few dozen KiB, anything much bigger isn't one
+const MAX_INPUT: u64 = 10 * 1024 * 1024;
+
+// The year, day and part of `/day/4/part/2`, or of `/2022/day/4/part/2` for
+// another year than the default
+fn route(path: &str, year: u16) -> Option<(u16, u8, u8)> {
+    let path = path.split('?').next().unwrap_or_default();
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+    match segments[..] {
+        ["day", day, "part", part] => Some((year, day.parse().ok()?, part.parse().ok()?)),
+        [year, "day", day, "part", part] => {
+            Some((year.parse().ok()?, day.parse().ok()?, part.parse().ok()?))
+        }
+        _ => None,
+    }
+}
+
+fn error(status: u16, e: &str) -> (u16, String) {
+    (status, json!({ "error": e }).to_string())
+}
+
+// The input in the body of a request, or the error to answer with. One byte
+// more than allowed is read to tell a body over the limit from one at it.
+fn read_input(body: impl Read) -> Result<String, (u16, String)> {
+    let mut input = Vec::new();
+    if let Err(e) = body.take(MAX_INPUT + 1).read_to_end(&mut input) {
+        return Err(error(400, &format!("read input: {e}")));
+    }
+    if input.len() as u64 > MAX_INPUT {
+        return Err(error(
+            413,
+            &format!("input over {}", memory::bytes(MAX_INPUT as usize)),
+        ));
+    }
+
+    String::from_utf8(input).map_err(|e| error(400, &format!("read input: {e}")))
+}
+
+// The status and JSON body answering a request
+fn answer(method: &Method, path: &str, input: &str, year: u16) -> (u16, String) {
+    let Some((year, day, part)) = route(path, year) else {
+        return error(404, "not found, POST the input to /day/{day}/part/{part}");
+    };
+    if *method != Method::Post {
+        return error(405, "POST the input");
+    }
+    let Some(solution) = registry::find(year, day) else {
+        return error(404, &format!("no solution for {year} day {day}"));
+    };
+
+    let input = normalize_newlines(input);
+    let start = Instant::now();
+    let parsed = match unwind(|| solution.parse(&input)) {
+        Ok(parsed) => parsed,
+        Err(e) => return error(422, &e.to_string()),
+    };
+    let parse_ms = millis(start.elapsed());
+
+    let start = Instant::now();
+    let answer = match unwind(|| solution.solve(part, &parsed)) {
+        Ok(answer) => answer,
+        Err(e) => return error(422, &e.to_string()),
+    };
+    let result = PartResult {
+        year,
+        day,
+        part,
+        answer,
+        parse_ms,
+        solve_ms: millis(start.elapsed()),
+        verdict: None,
+    };
+
+    match serde_json::to_string(&result) {
+        Ok(json) => (200, json),
+        Err(e) => error(500, &e.to_string()),
+    }
+}
+
+// Serves the solutions over HTTP until stopped, e.g.
+//
+//     curl --data-binary @input.txt localhost:8023/day/4/part/2
+//
+// answers with the same JSON record as `--output json`. Requests are handled
+// one at a time.
+pub fn serve(address: &str, year: u16) -> Result<(), AocError> {
+    let server = Server::http(address).map_err(|e| format!("listen on {address}: {e}"))?;
+    println!("Listening on http://{address}, Ctrl-C to stop");
+
+    let content_type = Header::from_bytes("Content-Type", "application/json")
+        .map_err(|_| "bad Content-Type header".to_string())?;
+    for mut request in server.incoming_requests() {
+        let (status, body) = match read_input(request.as_reader()) {
+            Ok(input) => answer(request.method(), request.url(), &input, year),
+            Err(e) => e,
+        };
+        println!("{} {} {status}", request.method(), request.url());
+
+        let response = Response::from_string(body)
+            .with_status_code(status)
+            .with_header(content_type.clone());
+        if let Err(e) = request.respond(response) {
+            eprintln!("respond: {e}");
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_routes_days_and_parts() {
+        assert_eq!(route("/day/4/part/2", 2023), Some((2023, 4, 2)));
+        assert_eq!(route("/2022/day/1/part/1/", 2023), Some((2022, 1, 1)));
+        assert_eq!(route("/day/four/part/2", 2023), None);
+        assert_eq!(route("/day/4/part/2?from=sam", 2023), Some((2023, 4, 2)));
+        assert_eq!(route("/day/4", 2023), None);
+    }
+
+    #[test]
+    fn it_answers_with_json() {
+        let card = "Card 1: 41 48 83 | 83 86 6 48\r\n";
+        let (status, body) = answer(&Method::Post, "/day/4/part/1", card, 2023);
+        assert_eq!(status, 200);
+        assert!(body.starts_with(r#"{"year":2023,"day":4,"part":1,"answer":2,"#));
+
+        let (status, body) = answer(&Method::Post, "/day/4/part/9", "", 2023);
+        assert_eq!(status, 422);
+        assert_eq!(body, r#"{"error":"no part 9"}"#);
+
+        let (status, body) = answer(&Method::Get, "/day/4/part/1", "", 2023);
+        assert_eq!(status, 405);
+        assert!(body.contains("POST"));
+
+        assert_eq!(answer(&Method::Post, "/day/24/part/1", "", 2023).0, 404);
+        assert_eq!(answer(&Method::Post, "/", "", 2023).0, 404);
+    }
+
+    #[test]
+    fn it_refuses_inputs_over_the_limit() {
+        let at_limit = std::io::repeat(b'1').take(MAX_INPUT);
+        assert_eq!(read_input(at_limit).map(|i| i.len() as u64), Ok(MAX_INPUT));
+
+        let (status, body) = read_input(std::io::repeat(b'1')).unwrap_err();
+        assert_eq!(status, 413);
+        assert_eq!(body, r#"{"error":"input over 10.0 MiB"}"#);
+
+        assert_eq!(read_input(&[0xff, 0xfe][..]).unwrap_err().0, 400);
+    }
+}
@@ -0,0 +1,131 @@
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+use crate::color;
+use crate::memory::{self, Usage};
+
+// Wall-clock time and memory used by each phase of a run, in the order they
+// ran. Memory is only known while the allocator is counting.
+#[derive(Default)]
+pub struct Timings(Vec<(String, Duration, Option<Usage>)>);
+
+impl Timings {
+    pub fn time<T>(&mut self, phase: &str, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let (result, usage) = memory::measure(f);
+        self.0.push((phase.to_string(), start.elapsed(), usage));
+
+        result
+    }
+
+    // Adds a phase timed elsewhere, whose memory use isn't known
+    pub fn add(&mut self, phase: &str, time: Duration) {
+        self.0.push((phase.to_string(), time, None));
+    }
+
+    pub fn get(&self, phase: &str) -> Option<Duration> {
+        self.0
+            .iter()
+            .find(|(p, _, _)| p == phase)
+            .map(|(_, time, _)| *time)
+    }
+
+    pub fn total(&self) -> Duration {
+        self.0.iter().map(|(_, time, _)| *time).sum()
+    }
+
+    // Everything allocated and the highest peak of any phase, if any was measured
+    fn total_usage(&self) -> Option<Usage> {
+        self.0
+            .iter()
+            .filter_map(|(_, _, usage)| *usage)
+            .reduce(|a, b| Usage {
+                allocated: a.allocated + b.allocated,
+                peak: a.peak.max(b.peak),
+            })
+    }
+}
+
+fn usage_columns(usage: Option<Usage>) -> (String, String) {
+    match usage {
+        Some(usage) => (memory::bytes(usage.allocated), memory::bytes(usage.peak)),
+        None => ("-".to_string(), "-".to_string()),
+    }
+}
+
+impl Display for Timings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self
+            .0
+            .iter()
+            .map(|(phase, _, _)| phase.len())
+            .chain(["total".len()])
+            .max()
+            .unwrap_or_default();
+
+        writeln!(
+            f,
+            "{:<width$}  {:>12}  {:>10}  {:>10}",
+            "phase", "time", "allocated", "peak"
+        )?;
+        for (phase, time, usage) in &self.0 {
+            let (allocated, peak) = usage_columns(*usage);
+            writeln!(
+                f,
+                "{phase:<width$}  {}  {allocated:>10}  {peak:>10}",
+                color::time(*time, format!("{:>12}", format!("{time:.3?}")))
+            )?;
+        }
+
+        let (allocated, peak) = usage_columns(self.total_usage());
+        writeln!(
+            f,
+            "{:<width$}  {:>12}  {allocated:>10}  {peak:>10}",
+            "total",
+            format!("{:.3?}", self.total())
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_formats_a_table() {
+        let usage = |allocated, peak| Some(Usage { allocated, peak });
+        let timings = Timings(vec![
+            (
+                "load input".to_string(),
+                Duration::from_micros(1500),
+                usage(2048, 1024),
+            ),
+            (
+                "part 1".to_string(),
+                Duration::from_millis(20),
+                usage(100, 3000),
+            ),
+            ("day 2".to_string(), Duration::from_millis(1), None),
+        ]);
+
+        assert_eq!(
+            timings.to_string(),
+            "\
+phase               time   allocated        peak
+load input       1.500ms     2.0 KiB     1.0 KiB
+part 1          20.000ms       100 B     2.9 KiB
+day 2            1.000ms           -           -
+total           22.500ms     2.1 KiB     2.9 KiB
+"
+        );
+    }
+
+    #[test]
+    fn it_times_phases() {
+        let mut timings = Timings::default();
+
+        assert_eq!(timings.time("part 1", || 42), 42);
+        assert_eq!(timings.0.len(), 1);
+    }
+}
@@ -0,0 +1,363 @@
+use std::process::{Command, Stdio};
+
+use aoc_common::client::puzzle_url;
+use aoc_common::{is_not_implemented, registry, AocError};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::widgets::{Block, Cell, Paragraph, Row, Table, TableState};
+use ratatui::{DefaultTerminal, Frame};
+use rusqlite::Connection;
+
+use crate::check::Check;
+use crate::history::{self, Run};
+use crate::report;
+
+const HELP: &str = "↑/↓ select  enter run  o open puzzle  q quit";
+
+// A day as listed on the dashboard
+#[derive(Debug, Default, PartialEq)]
+struct Day {
+    day: u8,
+    implemented: bool,
+    // The confirmed answer of each part, a part with one is solved
+    expected: [Option<String>; 2],
+    // The last recorded run of each part
+    last: [Option<Run>; 2],
+}
+
+impl Day {
+    fn load(check: &Check, db: Option<&Connection>, year: u16, day: u8) -> Day {
+        let last_run = |part| {
+            let runs = history::runs(db?, year, day, Some(part), 1).ok()?;
+            runs.into_iter().next()
+        };
+
+        Day {
+            day,
+            implemented: registry::find(year, day).is_some(),
+            expected: [1, 2].map(|part| check.expected(year, day, part)),
+            last: [1, 2].map(last_run),
+        }
+    }
+
+    fn status(&self) -> &'static str {
+        match (&self.expected, self.implemented) {
+            ([Some(_), Some(_)], _) => "solved",
+            ([Some(_), None], _) => "part 1 solved",
+            (_, true) => "unsolved",
+            (_, false) => "not started",
+        }
+    }
+
+    // The last answer of a part, green when it's the confirmed one and red
+    // when it isn't
+    fn answer(&self, part: usize) -> Cell<'static> {
+        let Some(run) = &self.last[part] else {
+            return Cell::from("");
+        };
+        let style = match &self.expected[part] {
+            Some(expected) if *expected == run.answer => Style::new().green(),
+            Some(_) => Style::new().red(),
+            None => Style::new(),
+        };
+
+        Cell::from(run.answer.clone()).style(style)
+    }
+
+    // How long the last run took, the input being parsed once for both parts
+    fn time(&self) -> String {
+        let runs: Vec<&Run> = self.last.iter().flatten().collect();
+        let Some(first) = runs.first() else {
+            return String::new();
+        };
+        let ms = first.parse_ms + runs.iter().map(|run| run.solve_ms).sum::<f64>();
+
+        format!("{ms:.3} ms")
+    }
+
+    fn row(&self) -> Row<'static> {
+        let stars: String = self
+            .expected
+            .iter()
+            .map(|expected| if expected.is_some() { '*' } else { '.' })
+            .collect();
+
+        Row::new([
+            Cell::from(format!("{:>3}", self.day)),
+            Cell::from(stars).yellow(),
+            Cell::from(self.status()),
+            self.answer(0),
+            self.answer(1),
+            Cell::from(format!("{:>12}", self.time())),
+        ])
+    }
+}
+
+// Every day of a year, with the selected one to run or open
+struct Dashboard {
+    year: u16,
+    days: Vec<Day>,
+    state: TableState,
+    status: String,
+    // Run on the next turn, once "Running..." has been drawn
+    pending: bool,
+    check: Check,
+    db: Option<Connection>,
+}
+
+impl Dashboard {
+    fn load(year: u16) -> Result<Dashboard, AocError> {
+        let check = Check::new()?;
+        // The dashboard still works without the history, just without answers
+        let (db, status) = match history::open() {
+            Ok(db) => (Some(db), HELP.to_string()),
+            Err(e) => (None, e.to_string()),
+        };
+        let days = (1..=25)
+            .map(|day| Day::load(&check, db.as_ref(), year, day))
+            .collect();
+
+        Ok(Dashboard {
+            year,
+            days,
+            state: TableState::default().with_selected(0),
+            status,
+            pending: false,
+            check,
+            db,
+        })
+    }
+
+    fn selected(&self) -> u8 {
+        self.state.selected().unwrap_or_default().min(24) as u8 + 1
+    }
+
+    // Returns whether to quit
+    fn handle(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => return true,
+            KeyCode::Down | KeyCode::Char('j') => self.state.select_next(),
+            KeyCode::Up | KeyCode::Char('k') => self.state.select_previous(),
+            KeyCode::Enter | KeyCode::Char('r') => {
+                self.status = format!("Running day {}...", self.selected());
+                self.pending = true;
+            }
+            KeyCode::Char('o') => self.open_puzzle(),
+            _ => {}
+        }
+
+        false
+    }
+
+    // Solves the selected day and records it, like `aoc report` does
+    fn run_selected(&mut self) {
+        let day = self.selected();
+        let solved = match report::solve(self.year, day) {
+            Ok(solved) => solved,
+            Err(e) => {
+                self.status = e.to_string();
+                return;
+            }
+        };
+
+        let mut results = Vec::new();
+        let mut errors = Vec::new();
+        for result in solved {
+            match result {
+                Ok(result) => results.push(result),
+                Err(e) if is_not_implemented(&e) => {}
+                Err(e) => errors.push(e),
+            }
+        }
+        let saved = match &self.db {
+            Some(db) => history::record(db, &results),
+            None => Err(AocError::from("no history to record the run in")),
+        };
+
+        self.status = match (errors.first(), saved) {
+            (Some(e), _) => e.to_string(),
+            (None, Err(e)) => format!("Not recorded in the history: {e}"),
+            (None, Ok(())) => format!("Ran day {day}"),
+        };
+        self.days[day as usize - 1] = Day::load(&self.check, self.db.as_ref(), self.year, day);
+    }
+
+    fn open_puzzle(&mut self) {
+        let url = puzzle_url(self.year, self.selected());
+        self.status = match open(&url) {
+            Ok(()) => format!("Opened {url}"),
+            Err(e) => e.to_string(),
+        };
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [table, status] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+
+        let header = Row::new(["day", "", "status", "part 1", "part 2", "time"]).bold();
+        let rows = self.days.iter().map(Day::row);
+        let widths = [
+            Constraint::Length(3),
+            Constraint::Length(2),
+            Constraint::Length(13),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Length(12),
+        ];
+        let days = Table::new(rows, widths)
+            .header(header)
+            .block(Block::bordered().title(format!(" Advent of Code {} ", self.year)))
+            .highlight_style(Style::new().reversed())
+            .highlight_symbol("> ");
+
+        frame.render_stateful_widget(days, table, &mut self.state);
+        frame.render_widget(Paragraph::new(self.status.as_str()).fg(Color::Gray), status);
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<(), AocError> {
+        loop {
+            terminal
+                .draw(|frame| self.draw(frame))
+                .map_err(|e| format!("draw dashboard: {e}"))?;
+            if self.pending {
+                self.pending = false;
+                self.run_selected();
+                continue;
+            }
+
+            match event::read().map_err(|e| format!("read key: {e}"))? {
+                Event::Key(key) if key.kind == KeyEventKind::Press && self.handle(key.code) => {
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+// Opens a URL in the default browser
+fn open(url: &str) -> Result<(), AocError> {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.args(["/C", "start", ""]);
+        command
+    } else {
+        Command::new("xdg-open")
+    };
+
+    command
+        .arg(url)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| AocError::from(format!("open {url}: {e}")))
+}
+
+// Shows every day of the year with its stars, last answers and timings,
+// until quit
+pub fn dashboard(year: u16) -> Result<(), AocError> {
+    let mut dashboard = Dashboard::load(year)?;
+
+    let mut terminal = ratatui::init();
+    let result = dashboard.run(&mut terminal);
+    ratatui::restore();
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn run(part: u8, answer: &str) -> Run {
+        Run {
+            time: "2023-12-04 06:00:00".to_string(),
+            git_commit: None,
+            part,
+            answer: answer.to_string(),
+            parse_ms: 0.5,
+            solve_ms: 1.0,
+        }
+    }
+
+    fn dashboard(days: Vec<Day>) -> Dashboard {
+        Dashboard {
+            year: 2023,
+            days,
+            state: TableState::default().with_selected(0),
+            status: HELP.to_string(),
+            pending: false,
+            check: Check::default(),
+            db: None,
+        }
+    }
+
+    #[test]
+    fn it_summarizes_days() {
+        let day = Day {
+            day: 4,
+            implemented: true,
+            expected: [Some("13".to_string()), None],
+            last: [Some(run(1, "13")), Some(run(2, "30"))],
+        };
+
+        assert_eq!(day.status(), "part 1 solved");
+        assert_eq!(day.time(), "2.500 ms");
+        assert_eq!(Day::default().status(), "not started");
+        assert_eq!(Day::default().time(), "");
+    }
+
+    #[test]
+    fn it_selects_days_from_the_keyboard() {
+        let mut dashboard = dashboard(
+            (1..=25)
+                .map(|day| Day {
+                    day,
+                    ..Day::default()
+                })
+                .collect(),
+        );
+
+        dashboard.handle(KeyCode::Down);
+        dashboard.handle(KeyCode::Char('j'));
+        dashboard.handle(KeyCode::Up);
+        assert_eq!(dashboard.selected(), 2);
+
+        assert!(!dashboard.handle(KeyCode::Enter));
+        assert!(dashboard.pending);
+        assert_eq!(dashboard.status, "Running day 2...");
+        assert!(dashboard.handle(KeyCode::Char('q')));
+    }
+
+    #[test]
+    fn it_draws_the_days() {
+        let mut dashboard = dashboard(vec![Day {
+            day: 4,
+            implemented: true,
+            expected: [Some("13".to_string()), Some("30".to_string())],
+            last: [Some(run(1, "13")), None],
+        }]);
+        let mut terminal = Terminal::new(TestBackend::new(80, 6)).unwrap();
+
+        terminal.draw(|frame| dashboard.draw(frame)).unwrap();
+
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(screen.contains("Advent of Code 2023"));
+        assert!(screen.contains("  4 ** solved"));
+        assert!(screen.contains("13"));
+        assert!(screen.contains(HELP));
+    }
+}
@@ -0,0 +1,175 @@
+use std::path::Path;
+use std::process::Command;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use aoc_common::input::{day_crate, day_dir, workspace_dir};
+use aoc_common::AocError;
+use notify::{EventKind, RecursiveMode, Watcher};
+
+// Editors save in bursts, writing a temporary file and renaming it over the
+// old one, so changes this close together only trigger one run
+const SETTLE: Duration = Duration::from_millis(200);
+
+// What to run on every save
+pub struct Watch {
+    pub year: u16,
+    pub day: u8,
+    pub part: Option<u8>,
+    pub example: Option<String>,
+    pub test: bool,
+}
+
+impl Watch {
+    // The cargo arguments that run the day. The runner is built again with
+    // the changed solution, so it's run through cargo rather than in process.
+    fn args(&self) -> Vec<String> {
+        if self.test {
+            return ["test", "--quiet", "-p", &day_crate(self.year, self.day)]
+                .map(String::from)
+                .to_vec();
+        }
+
+        let mut args: Vec<String> = ["run", "--quiet", "-p", "runner", "--"]
+            .map(String::from)
+            .to_vec();
+        args.extend([
+            "--year".to_string(),
+            self.year.to_string(),
+            "--day".to_string(),
+            self.day.to_string(),
+        ]);
+        if let Some(part) = self.part {
+            args.extend(["--part".to_string(), part.to_string()]);
+        }
+        match self.example.as_deref() {
+            Some("") => args.push("--example".to_string()),
+            Some(n) => args.extend(["--example".to_string(), n.to_string()]),
+            None => {}
+        }
+
+        args
+    }
+
+    fn run(&self) {
+        let args = self.args();
+        println!("Running cargo {}", args.join(" "));
+
+        // Failing to build or solve is part of the loop, only reported
+        match Command::new("cargo")
+            .args(&args)
+            .current_dir(workspace_dir())
+            .status()
+        {
+            Ok(status) if status.success() => {}
+            Ok(status) => println!("Failed: {status}"),
+            Err(e) => println!("Failed to run cargo: {e}"),
+        }
+        println!("Watching for changes, Ctrl-C to stop");
+    }
+
+    // Runs the day once and then again whenever a file of the day, its
+    // source or its inputs, changes. Only returns if watching fails.
+    pub fn watch(&self) -> Result<(), AocError> {
+        let dir = day_dir(self.year, self.day);
+        let (tx, rx) = mpsc::channel();
+        let mut watcher =
+            notify::recommended_watcher(tx).map_err(|e| format!("watch {}: {e}", dir.display()))?;
+        watcher
+            .watch(&dir, RecursiveMode::Recursive)
+            .map_err(|e| format!("watch {}: {e}", dir.display()))?;
+
+        self.run();
+        loop {
+            let event = rx
+                .recv()
+                .map_err(|e| format!("watch {}: {e}", dir.display()))?
+                .map_err(|e| format!("watch {}: {e}", dir.display()))?;
+            if !changes(&event) {
+                continue;
+            }
+
+            while rx.recv_timeout(SETTLE).is_ok() {}
+            self.run();
+        }
+    }
+}
+
+// Whether an event changed a file worth running again for. Reads, and the
+// swap and backup files editors keep next to the file, don't count.
+fn changes(event: &notify::Event) -> bool {
+    let kind = matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    );
+
+    kind && event.paths.iter().any(|path| !is_scratch(path))
+}
+
+fn is_scratch(path: &Path) -> bool {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+
+    name.starts_with('.') || name.ends_with('~') || name.ends_with(".swp")
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use notify::event::{AccessKind, ModifyKind};
+
+    fn watch(part: Option<u8>, example: Option<&str>, test: bool) -> Watch {
+        Watch {
+            year: 2023,
+            day: 4,
+            part,
+            example: example.map(String::from),
+            test,
+        }
+    }
+
+    #[test]
+    fn it_runs_the_day_through_cargo() {
+        assert_eq!(
+            watch(None, None, false).args().join(" "),
+            "run --quiet -p runner -- --year 2023 --day 4"
+        );
+        assert_eq!(
+            watch(Some(2), Some(""), false).args().join(" "),
+            "run --quiet -p runner -- --year 2023 --day 4 --part 2 --example"
+        );
+        assert_eq!(
+            watch(None, Some("2"), false).args().join(" "),
+            "run --quiet -p runner -- --year 2023 --day 4 --example 2"
+        );
+        assert_eq!(
+            watch(None, None, true).args().join(" "),
+            "test --quiet -p day_04"
+        );
+    }
+
+    #[test]
+    fn it_ignores_reads_and_scratch_files() {
+        let event = |kind, path: &str| notify::Event::new(kind).add_path(path.into());
+
+        assert!(changes(&event(
+            EventKind::Modify(ModifyKind::Any),
+            "day_04/src/lib.rs"
+        )));
+        assert!(!changes(&event(
+            EventKind::Access(AccessKind::Any),
+            "day_04/src/lib.rs"
+        )));
+        assert!(!changes(&event(
+            EventKind::Modify(ModifyKind::Any),
+            "day_04/src/.lib.rs.swp"
+        )));
+        assert!(!changes(&event(
+            EventKind::Modify(ModifyKind::Any),
+            "day_04/src/lib.rs~"
+        )));
+    }
+}
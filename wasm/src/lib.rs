@@ -0,0 +1,56 @@
+// The solutions built for WebAssembly, so the playground in `www` can solve
+// inputs in the browser:
+//
+//     wasm-pack build wasm --target web --out-dir www/pkg
+//     python3 -m http.server -d wasm/www
+use aoc_common::input::normalize_newlines;
+use aoc_common::{registry, AocError};
+use wasm_bindgen::prelude::*;
+
+// Days register themselves with aoc_common, they only need to be linked in
+use day_01 as _;
+use day_02 as _;
+use day_03 as _;
+use day_04 as _;
+use day_05 as _;
+
+fn answer(year: u16, day: u8, part: u8, input: &str) -> Result<String, AocError> {
+    let input = normalize_newlines(input);
+
+    registry::solve(year, day, part, &input).map(|answer| answer.to_string())
+}
+
+// Every registered day of a year
+#[wasm_bindgen]
+pub fn days(year: u16) -> Vec<u8> {
+    registry::days()
+        .into_iter()
+        .filter(|(y, _)| *y == year)
+        .map(|(_, day)| day)
+        .collect()
+}
+
+// Solves a part of a day, throwing the error when it fails
+#[wasm_bindgen]
+pub fn solve(year: u16, day: u8, part: u8, input: &str) -> Result<String, JsError> {
+    answer(year, day, part, input).map_err(JsError::from)
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_links_every_day() {
+        assert_eq!(days(2023), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn it_solves_pasted_inputs() {
+        let card = "Card 1: 41 48 83 | 83 86 6 48\r\n";
+
+        assert_eq!(answer(2023, 4, 1, card), Ok("2".to_string()));
+        assert!(answer(2023, 24, 1, card).is_err());
+    }
+}
@@ -0,0 +1,29 @@
+// Glue between the page and the solutions built by wasm-pack into ./pkg
+import init, { days, solve } from "./pkg/aoc_wasm.js";
+
+const YEAR = 2023;
+
+await init();
+
+const select = document.getElementById("day");
+for (const day of days(YEAR)) {
+  select.add(new Option(`Day ${day}`, day));
+}
+
+// A part that fails shows its error in place of the answer
+function answer(day, part, input) {
+  try {
+    return solve(YEAR, day, part, input);
+  } catch (e) {
+    return e.message;
+  }
+}
+
+document.getElementById("solve").addEventListener("click", () => {
+  const day = Number(select.value);
+  const input = document.getElementById("input").value;
+
+  document.getElementById("answers").textContent = [1, 2]
+    .map((part) => `Part ${part}: ${answer(day, part, input)}`)
+    .join("\n");
+});